@@ -0,0 +1,41 @@
+use crate::prelude::*;
+use alloy_primitives::{Address, Bloom, FixedBytes, Signed};
+
+// Same encoding as primitive-types, so that a B256 hashes the same as an H256,
+// and an Address the same as an H160. Uint is covered by the ruint feature.
+
+impl<const N: usize> StableHash for FixedBytes<N> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        AsBytes(self.as_slice()).stable_hash(field_address, state)
+    }
+}
+
+impl StableHash for Address {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.0.stable_hash(field_address, state)
+    }
+}
+
+impl StableHash for Bloom {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.0.stable_hash(field_address, state)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> StableHash for Signed<BITS, LIMBS> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        AsInt {
+            is_negative: self.is_negative(),
+            little_endian: &self.unsigned_abs().as_le_bytes(),
+        }
+        .stable_hash(field_address, state)
+    }
+}
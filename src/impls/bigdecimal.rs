@@ -0,0 +1,19 @@
+use crate::prelude::*;
+use bigdecimal::{num_bigint::Sign, BigDecimal};
+
+impl StableHash for BigDecimal {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        // AsDecimal normalizes the scale, so 1.50 and 1.5 hash the same.
+        // The cost depends on the size of the digits rather than the exponent,
+        // so a short value like 1e9223372036854775807 is cheap to hash.
+        let (int_val, scale) = self.as_bigint_and_exponent();
+        AsDecimal {
+            is_negative: int_val.sign() == Sign::Minus,
+            little_endian: &int_val.magnitude().to_bytes_le(),
+            scale,
+        }
+        .stable_hash(field_address, state)
+    }
+}
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use std::collections::BinaryHeap;
+
+impl<T: StableHash> StableHash for BinaryHeap<T> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        // The internal order of a heap depends on the order of insertion,
+        // so it is hashed as an unordered multiset.
+        crate::utils::AsUnorderedSet(self).stable_hash(field_address, state)
+    }
+}
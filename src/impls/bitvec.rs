@@ -0,0 +1,51 @@
+use crate::prelude::*;
+use bitvec::prelude::*;
+use bitvec::view::BitViewSized;
+
+/// Hashed the same as the unsigned integer with bit i set for each index i
+/// where the bit is 1, so trailing zero bits do not change the hash.
+/// The index is the logical index, so the BitOrder and BitStore do not matter.
+/// This is the same encoding as for fixedbitset.
+impl<T: BitStore, O: BitOrder> StableHash for BitSlice<T, O> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        let len = match self.last_one() {
+            Some(last) => last / 8 + 1,
+            None => return,
+        };
+        let mut little_endian = vec![0u8; len];
+        for index in self.iter_ones() {
+            little_endian[index / 8] |= 1 << (index % 8);
+        }
+        AsInt {
+            is_negative: false,
+            little_endian: &little_endian,
+        }
+        .stable_hash(field_address, state)
+    }
+}
+
+impl<T: BitStore, O: BitOrder> StableHash for BitVec<T, O> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.as_bitslice().stable_hash(field_address, state)
+    }
+}
+
+impl<T: BitStore, O: BitOrder> StableHash for BitBox<T, O> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.as_bitslice().stable_hash(field_address, state)
+    }
+}
+
+impl<A: BitViewSized, O: BitOrder> StableHash for BitArray<A, O> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.as_bitslice().stable_hash(field_address, state)
+    }
+}
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use std::collections::BTreeMap;
+
+impl<K: StableHash, V: StableHash> StableHash for BTreeMap<K, V> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        // Same as HashMap, so that the two are interchangeable.
+        // For the faster ordered encoding, see also AsSequence
+        crate::utils::AsUnorderedSet(self).stable_hash(field_address, state)
+    }
+}
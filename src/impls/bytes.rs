@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use bytes::{Bytes, BytesMut};
+
+/// Unlike Vec<u8>, which is a sequence of numbers, the buffer is opaque
+/// and is written with AsBytes. That means Bytes hashes the same as
+/// AsBytes(&vec[..]) but not as the Vec<u8> with the same contents.
+/// An empty buffer does not contribute to the hash.
+impl StableHash for Bytes {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        AsBytes(self).stable_hash(field_address, state)
+    }
+}
+
+/// Written with AsBytes, the same as Bytes.
+impl StableHash for BytesMut {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        AsBytes(self).stable_hash(field_address, state)
+    }
+}
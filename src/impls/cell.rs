@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use std::cell::{Cell, OnceCell, RefCell};
+
+// These hash the current contents, so they are transparent.
+
+impl<T: Copy + StableHash> StableHash for Cell<T> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.get().stable_hash(field_address, state)
+    }
+}
+
+/// Panics if the value is currently mutably borrowed.
+impl<T: StableHash + ?Sized> StableHash for RefCell<T> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.borrow().stable_hash(field_address, state)
+    }
+}
+
+/// Hashes the same as Option<T>, where an uninitialized cell is None.
+impl<T: StableHash> StableHash for OnceCell<T> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.get().stable_hash(field_address, state)
+    }
+}
@@ -0,0 +1,10 @@
+use crate::prelude::*;
+
+impl StableHash for char {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        // Written as the Unicode scalar value, so this is compatible with u32
+        (*self as u32).stable_hash(field_address, state)
+    }
+}
@@ -0,0 +1,65 @@
+use super::time::write_unix_nanos;
+use crate::prelude::*;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeZone};
+
+// Same encoding as SystemTime, so all time types are interchangeable.
+// chrono represents a leap second as subsecond nanos of at least 1_000_000_000 in the
+// preceding second. Those are written as the preceding second with a marker at child(4),
+// so that 23:59:60.5 does not collide with 23:59:59.5 or with 00:00:00.5 of the next day.
+fn write_utc<H: StableHasher>(date_time: &NaiveDateTime, field_address: H::Addr, state: &mut H) {
+    profile_fn!(write_utc);
+
+    let utc = date_time.and_utc();
+    let nanos = utc.timestamp_subsec_nanos();
+    let is_leap_second = nanos >= 1_000_000_000;
+    is_leap_second.stable_hash(field_address.child(4), state);
+    let unix_nanos = utc.timestamp() as i128 * 1_000_000_000 + (nanos % 1_000_000_000) as i128;
+    write_unix_nanos(unix_nanos, field_address, state)
+}
+
+/// Hashes the absolute instant, so equal instants in different offsets hash the same.
+/// To include the offset, see also WithOffset
+impl<Tz: TimeZone> StableHash for DateTime<Tz> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        write_utc(&self.naive_utc(), field_address, state)
+    }
+}
+
+impl<Tz: TimeZone> StableHash for WithOffset<&DateTime<Tz>> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        let offset = self.0.offset().fix().local_minus_utc();
+        offset.stable_hash(field_address.child(3), state);
+        self.0.stable_hash(field_address, state);
+    }
+}
+
+impl<Tz: TimeZone> StableHash for WithOffset<DateTime<Tz>> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        WithOffset(&self.0).stable_hash(field_address, state)
+    }
+}
+
+/// Hashed as the instant in UTC
+impl StableHash for NaiveDateTime {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        write_utc(self, field_address, state)
+    }
+}
+
+/// Hashed as midnight UTC at the start of the date
+impl StableHash for NaiveDate {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.and_time(NaiveTime::MIN)
+            .stable_hash(field_address, state)
+    }
+}
@@ -0,0 +1,22 @@
+use crate::prelude::*;
+use fixedbitset::FixedBitSet;
+
+/// Hashed the same as the unsigned integer with bit i set for each member i,
+/// so the length of the set (trailing zero bits) does not change the hash.
+/// This is the same encoding as for bitvec.
+impl StableHash for FixedBitSet {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        let little_endian: Vec<u8> = self
+            .as_slice()
+            .iter()
+            .flat_map(|block| block.to_le_bytes())
+            .collect();
+        AsInt {
+            is_negative: false,
+            little_endian: &little_endian,
+        }
+        .stable_hash(field_address, state)
+    }
+}
@@ -1,12 +1,14 @@
-// TODO: Implement stable_hash for f32 and f64.
-// For backward compatible migrations for arbitrary float precision to be satisfied,
-// it needs to be implemented using the following structs, which are similar to Integer<T>
-// TODO: +-0.0 compare the same, so have is_negative be false in that case.
+use crate::prelude::*;
+use ibig::UBig;
 
-/*
-enum Float<T> {
+// For backward compatible migrations between arbitrary float precisions, values
+// are first decomposed into the following structs, which are similar to AsInt.
+// Every f32 is exactly representable as an f64, so (x as f64) hashes the same as x.
+
+#[cfg_attr(test, derive(Debug, PartialEq))]
+enum Float {
     // Must be discriminant 0 for this to be the default
-    Number(Finite<T>),
+    Number(Finite),
     PosInfinity,
     NegInfinity,
     Nan,
@@ -14,11 +16,246 @@ enum Float<T> {
 
 // https://floating-point-gui.de/formats/fp/
 // https://evanw.github.io/float-toy/
-struct Finite<T> {
+#[cfg_attr(test, derive(Debug, PartialEq))]
+struct Finite {
     is_negative: bool,
-    exponent: i16, // This could be generic, but this fits all values required for f32 and f64
-    mantissa: T,   // Must be Borrow<[u8]>, should trim_zeroes when writing.
+    // The power of 2 of the most significant bit of the mantissa.
+    // This fits all values required for f32 and f64, including subnormals.
+    exponent: i16,
+    // Includes the implicit leading 1, and is aligned such that the most
+    // significant bit is the top bit of the u64. With this alignment, more
+    // precision only appends zeros which are trimmed when writing.
+    // The value 0 is reserved for +-0.0
+    mantissa: u64,
+}
+
+impl Float {
+    fn decompose(bits: u64, exponent_bits: u32, mantissa_bits: u32) -> Self {
+        profile_fn!(decompose);
+
+        let exponent_max = (1u64 << exponent_bits) - 1;
+        let bias = (exponent_max >> 1) as i16;
+
+        let is_negative = (bits >> (exponent_bits + mantissa_bits)) & 1 == 1;
+        let biased_exponent = (bits >> mantissa_bits) & exponent_max;
+        let fraction = bits & ((1u64 << mantissa_bits) - 1);
+
+        match (biased_exponent, fraction) {
+            // +-0.0 compare the same, so is_negative is false in that case.
+            (0, 0) => Float::Number(Finite {
+                is_negative: false,
+                exponent: 0,
+                mantissa: 0,
+            }),
+            // Subnormal. Normalize so that the same value has the same
+            // representation in a precision where it is not subnormal.
+            (0, fraction) => {
+                let leading = 63 - fraction.leading_zeros() as i16;
+                Float::Number(Finite {
+                    is_negative,
+                    exponent: 1 - bias - mantissa_bits as i16 + leading,
+                    mantissa: fraction << (63 - leading),
+                })
+            }
+            (e, 0) if e == exponent_max => {
+                if is_negative {
+                    Float::NegInfinity
+                } else {
+                    Float::PosInfinity
+                }
+            }
+            (e, _) if e == exponent_max => Float::Nan,
+            (e, fraction) => Float::Number(Finite {
+                is_negative,
+                exponent: e as i16 - bias,
+                mantissa: ((1u64 << mantissa_bits) | fraction) << (63 - mantissa_bits),
+            }),
+        }
+    }
+}
+
+impl StableHash for Finite {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        // +-0.0 contributes nothing, like other default values.
+        if self.mantissa == 0 {
+            return;
+        }
+        self.is_negative.stable_hash(field_address.child(0), state);
+        self.exponent.stable_hash(field_address.child(1), state);
+        // Big-endian, so that trimming removes the least significant bits.
+        // Never empty, because of the implicit leading 1.
+        let mantissa = self.mantissa.to_be_bytes();
+        state.write(field_address.child(2), trim_zeros(&mantissa));
+    }
+}
+
+impl StableHash for Float {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        let variant: u8 = match self {
+            Float::Number(finite) => {
+                finite.stable_hash(field_address.child(0), state);
+                0
+            }
+            Float::PosInfinity => 1,
+            Float::NegInfinity => 2,
+            Float::Nan => 3,
+        };
+        variant.stable_hash(field_address, state);
+    }
+}
+
+impl StableHash for f32 {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        Float::decompose(self.to_bits() as u64, 8, 23).stable_hash(field_address, state)
+    }
+}
+
+impl StableHash for f64 {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        Float::decompose(self.to_bits(), 11, 52).stable_hash(field_address, state)
+    }
+}
+
+impl Float {
+    // See also AsDecimal
+    fn as_number<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(as_number);
+
+        let variant: u8 = match self {
+            Float::Number(finite) => {
+                if finite.mantissa == 0 {
+                    return;
+                }
+                // The value is mantissa * 2^(exponent - 63), where the mantissa is
+                // reduced to be odd so that the decimal is in reduced form.
+                let trailing = finite.mantissa.trailing_zeros();
+                let mantissa = UBig::from(finite.mantissa >> trailing);
+                let exponent = finite.exponent as i64 - 63 + trailing as i64;
+                let (coefficient, scale) = if exponent >= 0 {
+                    (mantissa << exponent as usize, 0)
+                } else {
+                    // m * 2^-k = (m * 5^k) * 10^-k
+                    let k = exponent.unsigned_abs();
+                    (mantissa * UBig::from(5u8).pow(k as usize), k as i64)
+                };
+                return AsDecimal {
+                    is_negative: finite.is_negative,
+                    little_endian: &coefficient.to_le_bytes(),
+                    scale,
+                }
+                .stable_hash(field_address, state);
+            }
+            Float::PosInfinity => 1,
+            Float::NegInfinity => 2,
+            Float::Nan => 3,
+        };
+        variant.stable_hash(field_address.child(3), state);
+    }
+}
+
+impl StableHash for AsNumber<f32> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        Float::decompose(self.0.to_bits() as u64, 8, 23).as_number(field_address, state)
+    }
+}
+
+impl StableHash for AsNumber<f64> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        Float::decompose(self.0.to_bits(), 11, 52).as_number(field_address, state)
+    }
+}
+
+#[cfg(feature = "half")]
+impl StableHash for half::f16 {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        Float::decompose(self.to_bits() as u64, 5, 10).stable_hash(field_address, state)
+    }
+}
+
+#[cfg(feature = "half")]
+impl StableHash for half::bf16 {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        Float::decompose(self.to_bits() as u64, 8, 7).stable_hash(field_address, state)
+    }
 }
-*/
 
-// TODO: Test which exhaustively verifies all f32 bit patterns hash to the same values as (f32 as f64)
+#[cfg(feature = "half")]
+impl StableHash for AsNumber<half::f16> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        Float::decompose(self.0.to_bits() as u64, 5, 10).as_number(field_address, state)
+    }
+}
+
+#[cfg(feature = "half")]
+impl StableHash for AsNumber<half::bf16> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        Float::decompose(self.0.to_bits() as u64, 8, 7).as_number(field_address, state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Finite, Float};
+
+    fn decompose_f32(value: f32) -> Float {
+        Float::decompose(value.to_bits() as u64, 8, 23)
+    }
+
+    fn decompose_f64(value: f64) -> Float {
+        Float::decompose(value.to_bits(), 11, 52)
+    }
+
+    /// Verifies that all f32 bit patterns decompose to the same values as (f32 as f64),
+    /// which is what the hash is derived from.
+    /// This takes minutes, so run it explicitly with:
+    /// cargo test --release all_f32_same_as_f64 -- --ignored
+    #[test]
+    #[ignore = "exhaustive"]
+    fn all_f32_same_as_f64() {
+        // Split the work up, since there are 2^32 values to check.
+        let threads = 16u64;
+        let per_thread = (1u64 << 32) / threads;
+        std::thread::scope(|s| {
+            for t in 0..threads {
+                s.spawn(move || {
+                    for bits in (t * per_thread)..((t + 1) * per_thread) {
+                        let value = f32::from_bits(bits as u32);
+                        assert_eq!(decompose_f32(value), decompose_f64(value as f64));
+                    }
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn subnormals_normalize() {
+        assert_eq!(
+            decompose_f64(f64::from_bits(1)),
+            Float::Number(Finite {
+                is_negative: false,
+                exponent: -1074,
+                mantissa: 1 << 63,
+            })
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use ibig::{ops::UnsignedAbs, IBig, UBig};
+use num_traits::Signed;
+
+// These are written as AsInt, so an integer can grow from a fixed width
+// to arbitrary precision without changing the hash.
+
+impl StableHash for UBig {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        AsInt {
+            is_negative: false,
+            little_endian: &self.to_le_bytes(),
+        }
+        .stable_hash(field_address, state)
+    }
+}
+
+impl StableHash for IBig {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        AsInt {
+            is_negative: self.is_negative(),
+            little_endian: &self.unsigned_abs().to_le_bytes(),
+        }
+        .stable_hash(field_address, state)
+    }
+}
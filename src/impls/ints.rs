@@ -1,4 +1,8 @@
 use crate::prelude::*;
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
 
 macro_rules! impl_int {
     ($P:ty, $N:ty) => {
@@ -33,3 +37,29 @@ impl_int!(u32, i32);
 impl_int!(u16, i16);
 impl_int!(u8, i8);
 impl_int!(usize, isize);
+
+macro_rules! impl_non_zero {
+    ($P:ty, $N:ty) => {
+        impl StableHash for $P {
+            fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+                profile_method!(stable_hash);
+
+                self.get().stable_hash(field_address, state)
+            }
+        }
+        impl StableHash for $N {
+            fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+                profile_method!(stable_hash);
+
+                self.get().stable_hash(field_address, state)
+            }
+        }
+    };
+}
+
+impl_non_zero!(NonZeroU128, NonZeroI128);
+impl_non_zero!(NonZeroU64, NonZeroI64);
+impl_non_zero!(NonZeroU32, NonZeroI32);
+impl_non_zero!(NonZeroU16, NonZeroI16);
+impl_non_zero!(NonZeroU8, NonZeroI8);
+impl_non_zero!(NonZeroUsize, NonZeroIsize);
@@ -1,16 +1,63 @@
+#[cfg(feature = "alloy-primitives")]
+mod alloy_primitives;
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal;
+mod binary_heap;
+#[cfg(feature = "bitvec")]
+mod bitvec;
 mod bool;
+mod btree_map;
+mod btree_set;
+#[cfg(feature = "bytes")]
+mod bytes;
+mod cell;
+mod char;
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "fixedbitset")]
+mod fixedbitset;
 mod floats;
 mod hash_map;
 mod hash_set;
+mod ibig;
 mod ints;
+mod linked_list;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+mod net;
+#[cfg(feature = "num-bigint")]
+mod num_bigint;
+#[cfg(feature = "num-rational")]
+mod num_rational;
 mod option;
+mod ordering;
+mod pointers;
+#[cfg(feature = "primitive-types")]
+mod primitive_types;
+mod range;
+mod result;
+#[cfg(feature = "ruint")]
+mod ruint;
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal;
+#[cfg(feature = "serde_json")]
+mod serde_json;
 mod string;
+mod sync;
+mod time;
+#[cfg(feature = "time")]
+mod time_crate;
 mod tuple;
+mod unit;
+#[cfg(feature = "uuid")]
+mod uuid;
 mod vec;
+mod vec_deque;
+mod wrappers;
 
 use crate::prelude::*;
 
-impl<'a, T: StableHash> StableHash for &'a T {
+impl<'a, T: StableHash + ?Sized> StableHash for &'a T {
     #[inline]
     fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
         profile_method!(stable_hash);
@@ -18,3 +65,12 @@ impl<'a, T: StableHash> StableHash for &'a T {
         (*self).stable_hash(field_address, state)
     }
 }
+
+impl<T: StableHash + ?Sized> StableHash for &mut T {
+    #[inline]
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        (**self).stable_hash(field_address, state)
+    }
+}
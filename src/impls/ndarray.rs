@@ -0,0 +1,19 @@
+use crate::prelude::*;
+use ndarray::{ArrayBase, Data, Dimension};
+
+/// The shape is written as a slice at child(0) and the elements as a
+/// sequence at child(1). Elements are in logical row-major order regardless
+/// of the memory layout, so a view and its owned copy hash the same.
+impl<S, D> StableHash for ArrayBase<S, D>
+where
+    S: Data,
+    S::Elem: StableHash,
+    D: Dimension,
+{
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.shape().stable_hash(field_address.child(0), state);
+        AsSequence(self).stable_hash(field_address.child(1), state);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::prelude::*;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+// Addresses are opaque, so they are written as bytes in network order
+// rather than as numbers.
+
+/// Written as the 4 octets, in network order.
+///
+/// An Ipv4Addr never hashes the same as its IPv4-mapped Ipv6Addr (eg: 1.2.3.4 and
+/// ::ffff:1.2.3.4). If that equivalence is desired, use Ipv4Addr::to_ipv6_mapped
+/// or Ipv6Addr::to_canonical before hashing.
+impl StableHash for Ipv4Addr {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        AsBytes(&self.octets()).stable_hash(field_address, state)
+    }
+}
+
+/// Written as the 16 octets, in network order.
+impl StableHash for Ipv6Addr {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        AsBytes(&self.octets()).stable_hash(field_address, state)
+    }
+}
+
+/// V4 is written at child(0), and V6 at child(1). So, as with Ipv4Addr,
+/// IpAddr::V4(1.2.3.4) does not hash the same as IpAddr::V6(::ffff:1.2.3.4).
+/// Use IpAddr::to_canonical before hashing if that equivalence is desired.
+impl StableHash for IpAddr {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        match self {
+            IpAddr::V4(ip) => ip.stable_hash(field_address.child(0), state),
+            IpAddr::V6(ip) => ip.stable_hash(field_address.child(1), state),
+        }
+    }
+}
+
+impl StableHash for SocketAddrV4 {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.ip().stable_hash(field_address.child(0), state);
+        self.port().stable_hash(field_address.child(1), state);
+    }
+}
+
+impl StableHash for SocketAddrV6 {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.ip().stable_hash(field_address.child(0), state);
+        self.port().stable_hash(field_address.child(1), state);
+        self.flowinfo().stable_hash(field_address.child(2), state);
+        self.scope_id().stable_hash(field_address.child(3), state);
+    }
+}
+
+impl StableHash for SocketAddr {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        // Uses the same child addresses for each variant as IpAddr
+        match self {
+            SocketAddr::V4(addr) => addr.stable_hash(field_address.child(0), state),
+            SocketAddr::V6(addr) => addr.stable_hash(field_address.child(1), state),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use num_bigint::{BigInt, BigUint, Sign};
+
+// Same encoding as the primitive ints, via AsInt.
+
+impl StableHash for BigUint {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        AsInt {
+            is_negative: false,
+            little_endian: &self.to_bytes_le(),
+        }
+        .stable_hash(field_address, state)
+    }
+}
+
+impl StableHash for BigInt {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        AsInt {
+            is_negative: self.sign() == Sign::Minus,
+            little_endian: &self.magnitude().to_bytes_le(),
+        }
+        .stable_hash(field_address, state)
+    }
+}
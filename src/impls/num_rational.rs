@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use num_integer::Integer;
+use num_rational::Ratio;
+
+impl<T: Clone + Integer + StableHash> StableHash for Ratio<T> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        // Hashed in canonical form, so that 2/4 and 1/2 hash the same.
+        // This also ensures the denominator is positive. Like reduced,
+        // this panics if the denominator is zero. It also overflows (which panics
+        // in debug builds) if the reduced value does not fit in T, for example
+        // Ratio::new_raw(i32::MIN, -1).
+        let reduced = self.reduced();
+
+        if !reduced.is_integer() {
+            reduced.denom().stable_hash(field_address.child(1), state);
+        }
+        // The numerator is written in place of the value, so that
+        // an integral ratio hashes the same as the integer.
+        reduced.numer().stable_hash(field_address, state);
+    }
+}
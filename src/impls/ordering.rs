@@ -0,0 +1,11 @@
+use crate::prelude::*;
+use std::cmp::Ordering;
+
+impl StableHash for Ordering {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        // Written as -1, 0, or 1, like i8. Equal does not contribute to the hash.
+        (*self as i8).stable_hash(field_address, state)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::prelude::*;
+use std::borrow::{Cow, ToOwned};
+use std::rc::Rc;
+use std::sync::Arc;
+
+// Smart pointers are transparent, so that changing the ownership
+// model of a field does not change the hash.
+
+macro_rules! impl_pointer {
+    ($P:ident) => {
+        impl<T: StableHash + ?Sized> StableHash for $P<T> {
+            #[inline]
+            fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+                profile_method!(stable_hash);
+
+                (**self).stable_hash(field_address, state)
+            }
+        }
+    };
+}
+
+impl_pointer!(Box);
+impl_pointer!(Rc);
+impl_pointer!(Arc);
+
+impl<T: StableHash + ToOwned + ?Sized> StableHash for Cow<'_, T> {
+    #[inline]
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        (**self).stable_hash(field_address, state)
+    }
+}
@@ -0,0 +1,40 @@
+use crate::prelude::*;
+use primitive_types::{H128, H160, H256, H384, H512, H768, U128, U256, U512};
+
+// Integers are numbers, so a U256 holding a small value
+// hashes the same as the same value in a u64.
+macro_rules! impl_uint {
+    ($($T:ty),*) => {
+        $(
+            impl StableHash for $T {
+                fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+                    profile_method!(stable_hash);
+
+                    AsInt {
+                        is_negative: false,
+                        little_endian: &self.to_little_endian(),
+                    }
+                    .stable_hash(field_address, state)
+                }
+            }
+        )*
+    };
+}
+
+// Hashes (and addresses) are opaque, so they are written as bytes.
+macro_rules! impl_hash {
+    ($($T:ty),*) => {
+        $(
+            impl StableHash for $T {
+                fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+                    profile_method!(stable_hash);
+
+                    AsBytes(self.as_bytes()).stable_hash(field_address, state)
+                }
+            }
+        )*
+    };
+}
+
+impl_uint!(U128, U256, U512);
+impl_hash!(H128, H160, H256, H384, H512, H768);
@@ -0,0 +1,42 @@
+use crate::prelude::*;
+use std::ops::{Bound, Range, RangeInclusive};
+
+impl<T: StableHash> StableHash for Range<T> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.start.stable_hash(field_address.child(0), state);
+        self.end.stable_hash(field_address.child(1), state);
+    }
+}
+
+impl<T: StableHash> StableHash for RangeInclusive<T> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.start().stable_hash(field_address.child(0), state);
+        self.end().stable_hash(field_address.child(1), state);
+        // The marker disambiguates from Range, so that 0..0 (which is empty
+        // and does not contribute to the hash) and 0..=0 do not collide.
+        state.write(field_address, &[]);
+    }
+}
+
+impl<T: StableHash> StableHash for Bound<T> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        // Like Result, with Unbounded in place of None.
+        match self {
+            Bound::Included(value) => {
+                value.stable_hash(field_address.child(0), state);
+                state.write(field_address, &[0]);
+            }
+            Bound::Excluded(value) => {
+                value.stable_hash(field_address.child(1), state);
+                state.write(field_address, &[1]);
+            }
+            Bound::Unbounded => {}
+        }
+    }
+}
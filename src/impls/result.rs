@@ -0,0 +1,21 @@
+use crate::prelude::*;
+
+impl<T: StableHash, E: StableHash> StableHash for Result<T, E> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        // Like Option, the marker is necessary so that Ok(0) and Err(0)
+        // contribute to the hash. The marker differs from the one used by
+        // Option so that Ok(x) and Some(x) do not collide.
+        match self {
+            Ok(value) => {
+                value.stable_hash(field_address.child(0), state);
+                state.write(field_address, &[0]);
+            }
+            Err(err) => {
+                err.stable_hash(field_address.child(1), state);
+                state.write(field_address, &[1]);
+            }
+        }
+    }
+}
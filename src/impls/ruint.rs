@@ -0,0 +1,15 @@
+use crate::prelude::*;
+use ruint::Uint;
+
+// Same encoding as primitive-types, so that U256 from either crate hashes the same.
+impl<const BITS: usize, const LIMBS: usize> StableHash for Uint<BITS, LIMBS> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        AsInt {
+            is_negative: false,
+            little_endian: &self.as_le_bytes(),
+        }
+        .stable_hash(field_address, state)
+    }
+}
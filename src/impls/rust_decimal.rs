@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use rust_decimal::Decimal;
+
+impl StableHash for Decimal {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        // Same encoding as BigDecimal. AsDecimal normalizes the scale,
+        // so 1.50 and 1.5 hash the same.
+        AsDecimal {
+            // Decimal supports -0, which must hash the same as 0
+            is_negative: self.is_sign_negative() && !self.is_zero(),
+            little_endian: &self.mantissa().unsigned_abs().to_le_bytes(),
+            scale: self.scale() as i64,
+        }
+        .stable_hash(field_address, state)
+    }
+}
@@ -0,0 +1,127 @@
+use crate::prelude::*;
+use serde_json::{Map, Number, Value};
+
+/// Integers are written as AsInt and other numbers as AsNumber,
+/// so 1 and 1.0 hash the same as each other and as 1u64.
+///
+/// If serde_json's arbitrary_precision feature is enabled, enable the
+/// serde_json-arbitrary-precision feature of this crate as well. Then integers
+/// which do not fit in 64 bits and numbers which overflow f64 (like 1e400) are
+/// written exactly with AsDecimal, rather than through a lossy f64. A number with
+/// an exponent too large for the scale of AsDecimal (beyond i64) panics.
+/// Without it, those integers are rounded to the nearest f64 and hashing a number
+/// which overflows f64 panics.
+impl StableHash for Number {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        if let Some(value) = self.as_u64() {
+            return value.stable_hash(field_address, state);
+        }
+        if let Some(value) = self.as_i64() {
+            return value.stable_hash(field_address, state);
+        }
+
+        #[cfg(feature = "serde_json-arbitrary-precision")]
+        {
+            let text = self.to_string();
+            // Only floats in the JSON text go through f64, so that they hash
+            // the same as without arbitrary_precision.
+            let is_float = text.contains(['.', 'e', 'E']);
+            match self.as_f64() {
+                Some(value) if is_float => AsNumber(value).stable_hash(field_address, state),
+                _ => write_json_decimal(&text, field_address, state),
+            }
+        }
+
+        #[cfg(not(feature = "serde_json-arbitrary-precision"))]
+        match self.as_f64() {
+            Some(value) => AsNumber(value).stable_hash(field_address, state),
+            // Only possible if arbitrary_precision is enabled by another crate.
+            None => unreachable!(
+                "Cannot hash JSON number {} without the serde_json-arbitrary-precision feature",
+                self
+            ),
+        }
+    }
+}
+
+/// Writes a number in JSON syntax (-?int(.frac)?([eE][+-]?exp)?) exactly with AsDecimal.
+#[cfg(feature = "serde_json-arbitrary-precision")]
+fn write_json_decimal<H: StableHasher>(text: &str, field_address: H::Addr, state: &mut H) {
+    profile_fn!(write_json_decimal);
+
+    let (is_negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let (mantissa, exponent) = match text.find(['e', 'E']) {
+        Some(i) => (&text[..i], &text[i + 1..]),
+        None => (text, "0"),
+    };
+    let (int, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let exponent: i128 = exponent
+        .trim_start_matches('+')
+        .parse()
+        .unwrap_or_else(|_| panic!("Cannot hash JSON number with exponent {}", exponent));
+    let scale = i64::try_from(frac.len() as i128 - exponent)
+        .unwrap_or_else(|_| panic!("Cannot hash JSON number with exponent {}", exponent));
+
+    let digits = [int, frac].concat();
+    let coefficient = ibig::UBig::from_str_radix(&digits, 10).expect("JSON number digits");
+    AsDecimal {
+        is_negative,
+        little_endian: &coefficient.to_le_bytes(),
+        scale,
+    }
+    .stable_hash(field_address, state)
+}
+
+/// Hashed as an unordered set of (key, value), the same as a HashMap.
+/// So, objects which differ only in key order hash the same.
+impl StableHash for Map<String, Value> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        AsUnorderedSet(self).stable_hash(field_address, state)
+    }
+}
+
+/// The mapping from JSON to field addresses is:
+/// * Null does not contribute to the hash, like None.
+/// * Otherwise, the variant tag is written to the field address
+///   (Bool: 1, Number: 2, String: 3, Array: 4, Object: 5)
+///   and the payload is written to child(0).
+/// * Bool, String, and Array payloads are hashed like bool, String, and Vec<Value>.
+/// * Number payloads are hashed like serde_json::Number.
+/// * Object payloads are hashed like serde_json::Map.
+impl StableHash for Value {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        let variant = match self {
+            Value::Null => return,
+            Value::Bool(value) => {
+                value.stable_hash(field_address.child(0), state);
+                1
+            }
+            Value::Number(value) => {
+                value.stable_hash(field_address.child(0), state);
+                2
+            }
+            Value::String(value) => {
+                value.stable_hash(field_address.child(0), state);
+                3
+            }
+            Value::Array(value) => {
+                value.stable_hash(field_address.child(0), state);
+                4
+            }
+            Value::Object(value) => {
+                value.stable_hash(field_address.child(0), state);
+                5
+            }
+        };
+        state.write(field_address, &[variant]);
+    }
+}
@@ -8,7 +8,7 @@ impl StableHash for String {
     }
 }
 
-impl<'a> StableHash for &'a str {
+impl StableHash for str {
     fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
         profile_method!(stable_hash);
 
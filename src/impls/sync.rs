@@ -0,0 +1,37 @@
+use crate::prelude::*;
+use std::sync::{Mutex, OnceLock, RwLock};
+
+// These hash the current contents, so they are transparent. Hashing
+// while the current thread holds a conflicting lock will deadlock or panic
+// as described by the lock method of each type.
+
+/// Panics if the lock is poisoned, since the contents may be inconsistent.
+impl<T: StableHash + ?Sized> StableHash for Mutex<T> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.lock()
+            .expect("Cannot hash a poisoned Mutex")
+            .stable_hash(field_address, state)
+    }
+}
+
+/// Panics if the lock is poisoned, since the contents may be inconsistent.
+impl<T: StableHash + ?Sized> StableHash for RwLock<T> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.read()
+            .expect("Cannot hash a poisoned RwLock")
+            .stable_hash(field_address, state)
+    }
+}
+
+/// Hashes the same as Option<T>, where an uninitialized cell is None.
+impl<T: StableHash> StableHash for OnceLock<T> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.get().stable_hash(field_address, state)
+    }
+}
@@ -0,0 +1,77 @@
+use crate::prelude::*;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The canonical encoding for all time types, so that they are interchangeable.
+/// An instant is written as the distance from the Unix epoch, which does not depend
+/// on the platform.
+///
+/// Like AsInt, the negative sign is child(0), which is used for instants before
+/// the epoch. Seconds are child(1) and subsecond nanoseconds are child(2).
+/// Other time types may add child(3) for the offset from UTC (WithOffset)
+/// and child(4) to mark a leap second (chrono).
+pub(crate) fn write_time<H: StableHasher>(
+    is_negative: bool,
+    seconds: u64,
+    nanos: u32,
+    field_address: H::Addr,
+    state: &mut H,
+) {
+    profile_fn!(write_time);
+
+    if is_negative {
+        state.write(field_address.child(0), &[]);
+    }
+    seconds.stable_hash(field_address.child(1), state);
+    nanos.stable_hash(field_address.child(2), state);
+}
+
+/// Like write_time, for an instant given in nanoseconds since the Unix epoch.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub(crate) fn write_unix_nanos<H: StableHasher>(
+    nanos: i128,
+    field_address: H::Addr,
+    state: &mut H,
+) {
+    profile_fn!(write_unix_nanos);
+
+    let magnitude = nanos.unsigned_abs();
+    write_time(
+        nanos < 0,
+        (magnitude / 1_000_000_000) as u64,
+        (magnitude % 1_000_000_000) as u32,
+        field_address,
+        state,
+    )
+}
+
+impl StableHash for Duration {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        write_time(
+            false,
+            self.as_secs(),
+            self.subsec_nanos(),
+            field_address,
+            state,
+        )
+    }
+}
+
+impl StableHash for SystemTime {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        let (is_negative, duration) = match self.duration_since(UNIX_EPOCH) {
+            Ok(duration) => (false, duration),
+            Err(err) => (true, err.duration()),
+        };
+        write_time(
+            is_negative,
+            duration.as_secs(),
+            duration.subsec_nanos(),
+            field_address,
+            state,
+        )
+    }
+}
@@ -0,0 +1,51 @@
+use super::time::write_unix_nanos;
+use crate::prelude::*;
+use ::time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+// Same encoding as SystemTime, so all time types are interchangeable.
+
+/// Hashes the absolute instant, so equal instants in different offsets hash the same.
+/// To include the offset, see also WithOffset
+impl StableHash for OffsetDateTime {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        write_unix_nanos(self.unix_timestamp_nanos(), field_address, state)
+    }
+}
+
+impl StableHash for WithOffset<&OffsetDateTime> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        let offset = self.0.offset().whole_seconds();
+        offset.stable_hash(field_address.child(3), state);
+        self.0.stable_hash(field_address, state);
+    }
+}
+
+impl StableHash for WithOffset<OffsetDateTime> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        WithOffset(&self.0).stable_hash(field_address, state)
+    }
+}
+
+/// Hashed as the instant in UTC
+impl StableHash for PrimitiveDateTime {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.assume_utc().stable_hash(field_address, state)
+    }
+}
+
+/// Hashed as midnight UTC at the start of the date
+impl StableHash for Date {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        PrimitiveDateTime::new(*self, Time::MIDNIGHT).stable_hash(field_address, state)
+    }
+}
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use std::marker::PhantomData;
+
+impl StableHash for () {
+    #[inline]
+    fn stable_hash<H: StableHasher>(&self, _field_address: H::Addr, _state: &mut H) {}
+}
+
+impl<T: ?Sized> StableHash for PhantomData<T> {
+    #[inline]
+    fn stable_hash<H: StableHasher>(&self, _field_address: H::Addr, _state: &mut H) {}
+}
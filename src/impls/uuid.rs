@@ -0,0 +1,11 @@
+use crate::prelude::*;
+use uuid::Uuid;
+
+impl StableHash for Uuid {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        // Always 16 bytes, so the nil UUID still contributes to the hash.
+        AsBytes(self.as_bytes()).stable_hash(field_address, state)
+    }
+}
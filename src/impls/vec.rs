@@ -4,11 +4,19 @@ impl<T: StableHash> StableHash for Vec<T> {
     fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
         profile_method!(stable_hash);
 
-        (&self[..]).stable_hash(field_address, state)
+        self[..].stable_hash(field_address, state)
     }
 }
 
-impl<'a, T: StableHash> StableHash for &'a [T] {
+impl<T: StableHash, const N: usize> StableHash for [T; N] {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self[..].stable_hash(field_address, state)
+    }
+}
+
+impl<T: StableHash> StableHash for [T] {
     fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
         profile_method!(stable_hash);
 
@@ -0,0 +1,11 @@
+use crate::prelude::*;
+use std::collections::VecDeque;
+
+impl<T: StableHash> StableHash for VecDeque<T> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        // Same as Vec, in logical order regardless of where the ring buffer wraps.
+        crate::utils::AsSequence(self).stable_hash(field_address, state)
+    }
+}
@@ -0,0 +1,23 @@
+use crate::prelude::*;
+use std::cmp::Reverse;
+use std::num::{Saturating, Wrapping};
+
+// These only change the behavior of operations on the value,
+// so they are transparent.
+
+macro_rules! impl_wrapper {
+    ($W:ident) => {
+        impl<T: StableHash> StableHash for $W<T> {
+            #[inline]
+            fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+                profile_method!(stable_hash);
+
+                self.0.stable_hash(field_address, state)
+            }
+        }
+    };
+}
+
+impl_wrapper!(Wrapping);
+impl_wrapper!(Saturating);
+impl_wrapper!(Reverse);
@@ -23,6 +23,11 @@ pub mod utils;
 mod verification;
 use prelude::*;
 
+/// Derives StableHash for a struct with explicit field indices.
+/// See the stable-hash-derive crate for the supported attributes.
+#[cfg(feature = "derive")]
+pub use stable_hash_derive::StableHash;
+
 /// Like Hasher, but consistent across:
 /// * builds (independent of rustc version or std implementation details)
 /// * platforms (eg: 32 bit & 64 bit, x68 and ARM)
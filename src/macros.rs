@@ -3,7 +3,9 @@
 /// an optional modifier. For example: Tuple(transparent: AsBytes)
 ///
 /// This API is unstable and will likely be modified for a 1.0 release.
-/// It's just a stub to cover some common cases.
+/// It's just a stub to cover some common cases. Note that fields are numbered by
+/// their declaration order, so reordering fields changes the hash. The derive
+/// feature provides #[derive(StableHash)] with explicit field indices instead.
 #[macro_export]
 macro_rules! impl_stable_hash {
     ($T:ident$(<$lt:lifetime>)? {$($field:ident$(:$e:path)?),*}) => {
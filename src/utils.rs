@@ -1,5 +1,9 @@
 use crate::prelude::*;
 use crate::verification::*;
+use ibig::UBig;
+use std::ffi::{OsStr, OsString};
+use std::fmt;
+use std::path::{Component, Path};
 
 /// Treat some &[u8] as a sequence of bytes, rather than a sequence of numbers.
 /// Using this can result in a significant performance gain but does not support
@@ -16,7 +20,7 @@ impl StableHash for AsBytes<'_> {
     }
 }
 
-fn trim_zeros(bytes: &[u8]) -> &[u8] {
+pub(crate) fn trim_zeros(bytes: &[u8]) -> &[u8] {
     profile_fn!(trim_zeros);
 
     let mut end = bytes.len();
@@ -54,6 +58,209 @@ impl StableHash for AsInt<'_> {
     }
 }
 
+/// The most trailing zeros that AsDecimal appends to write an integral value as AsInt.
+pub const MAX_DECIMAL_EXPANSION: u32 = 4096;
+
+/// Canonical way to write a number which may not be an integer, like a decimal or a float.
+/// The value is `little_endian * 10^-scale`.
+///
+/// Backward compatibility:
+/// * Integral values (including 1.00, or 1E+2) are written exactly as AsInt. So, it is possible
+///   to change the schema from an integer to a float or decimal without changing the hash,
+///   as long as the values are integral.
+/// * Non-integral values are written in a reduced form (1.50 is written as 1.5), with the
+///   coefficient and scale as separate children so they cannot collide with an integer.
+/// * Like AsInt, the negative sign is a child. The value +0 never writes bytes to the stream.
+///
+/// The encoding of a value is:
+/// * child(0): negative sign, as in AsInt
+/// * self: the integer, if integral, as in AsInt
+/// * child(1): the coefficient, if not integral
+/// * child(2): the scale, if not integral
+/// * child(3): the kind of non-finite value (only written by AsNumber)
+///
+/// To keep the cost proportional to the size of the coefficient rather than
+/// the scale, an integer with more than MAX_DECIMAL_EXPANSION trailing zeros
+/// (like 1E+5000) is not expanded. Instead, it is written like a non-integral
+/// value, as the reduced coefficient with a negative scale. This depends only
+/// on the value, so it is the same when the integer is given with a scale of 0.
+/// Such an integer does not hash the same as the equal AsInt.
+pub struct AsDecimal<'a> {
+    pub is_negative: bool,
+    pub little_endian: &'a [u8],
+    pub scale: i64,
+}
+
+impl StableHash for AsDecimal<'_> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        let mut coefficient = UBig::from_le_bytes(self.little_endian);
+
+        // Zero is always integral, regardless of the scale.
+        if coefficient == UBig::from(0u8) {
+            return AsInt {
+                is_negative: self.is_negative,
+                little_endian: &[],
+            }
+            .stable_hash(field_address, state);
+        }
+
+        // Reduce to a coefficient without trailing zeros. The coefficient is not zero,
+        // so this is bounded by its number of digits rather than by the scale.
+        // i128, so that the scale cannot overflow.
+        let mut scale = self.scale as i128;
+        while (&coefficient % 10u8) == 0 {
+            coefficient /= 10u8;
+            scale -= 1;
+        }
+
+        // An integral value is expanded and written as AsInt, unless that would
+        // mean appending more than MAX_DECIMAL_EXPANSION zeros. This depends only
+        // on the value, so it applies even if the given scale is 0.
+        if (-(MAX_DECIMAL_EXPANSION as i128)..=0).contains(&scale) {
+            // With a scale of 0 the given bytes are already the integer.
+            if self.scale == 0 {
+                return AsInt {
+                    is_negative: self.is_negative,
+                    little_endian: self.little_endian,
+                }
+                .stable_hash(field_address, state);
+            }
+            coefficient *= UBig::from(10u8).pow(scale.unsigned_abs() as usize);
+            return AsInt {
+                is_negative: self.is_negative,
+                little_endian: &coefficient.to_le_bytes(),
+            }
+            .stable_hash(field_address, state);
+        }
+
+        self.is_negative.stable_hash(field_address.child(0), state);
+        AsInt {
+            is_negative: false,
+            little_endian: &coefficient.to_le_bytes(),
+        }
+        .stable_hash(field_address.child(1), state);
+        scale.stable_hash(field_address.child(2), state);
+    }
+}
+
+/// Writes a number using the encoding of AsDecimal, rather than the default encoding
+/// for its type. This makes it possible to change the schema between number
+/// families. For example, from i64 to f64, or from u64 to a decimal.
+///
+/// Floats are converted to a decimal exactly. NaN and +-infinity are written as
+/// distinct variants at child(3) of AsDecimal.
+///
+/// Note that the default encoding for floats is different, because it supports
+/// the backward compatible change between float precisions in a cheaper way.
+pub struct AsNumber<T>(pub T);
+
+impl<T: Copy> StableHash for AsNumber<&T>
+where
+    AsNumber<T>: StableHash,
+{
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        AsNumber(*self.0).stable_hash(field_address, state)
+    }
+}
+
+/// By default, date-times with an offset (like chrono::DateTime or time::OffsetDateTime) hash
+/// the absolute instant, so equal instants in different offsets hash the same. This includes the
+/// offset from UTC in the hash as well, in seconds.
+///
+/// An offset of 0 does not contribute to the hash, so a UTC time hashes the same with or
+/// without this wrapper.
+#[cfg(any(feature = "chrono", feature = "time"))]
+pub struct WithOffset<T>(pub T);
+
+// OsStr has no stable representation across platforms, so paths and OsStr are
+// only supported as UTF-8. Rather than using a lossy conversion (which could
+// cause collisions) or panicking, there are no StableHash impls for Path, PathBuf,
+// OsStr or OsString. Instead, convert with AsPath::try_from or AsOsStr::try_from,
+// which return NonUtf8Error for non-UTF-8 values.
+
+/// The error when a path or OsStr is not UTF-8, and so cannot be hashed. Holds the value.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct NonUtf8Error(pub OsString);
+
+impl fmt::Display for NonUtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value is not valid UTF-8: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for NonUtf8Error {}
+
+/// A path as a sequence of UTF-8 components, which hashes the same as a Vec<&str>
+/// of the components. This makes the hash independent of the platform. For example,
+/// a/b hashes the same as vec!["a", "b"] on every platform, even where the separator is \.
+///
+/// Components are as returned by Path::components, so a/./b and a//b/ are the same
+/// as a/b. The root is written as "/", and the current and parent directories
+/// as "." and "..".
+///
+/// Construct with try_from, which fails for non-UTF-8 paths. There is no StableHash
+/// impl for Path or PathBuf, so that failure is always handled by the caller.
+pub struct AsPath<'a>(Vec<&'a str>);
+
+impl<'a> TryFrom<&'a Path> for AsPath<'a> {
+    type Error = NonUtf8Error;
+
+    fn try_from(path: &'a Path) -> Result<Self, Self::Error> {
+        profile_method!(try_from);
+
+        path.components()
+            .map(|component| match component {
+                Component::RootDir => Some("/"),
+                Component::CurDir => Some("."),
+                Component::ParentDir => Some(".."),
+                Component::Prefix(prefix) => prefix.as_os_str().to_str(),
+                Component::Normal(normal) => normal.to_str(),
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(AsPath)
+            .ok_or_else(|| NonUtf8Error(path.as_os_str().to_owned()))
+    }
+}
+
+impl StableHash for AsPath<'_> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.0.stable_hash(field_address, state)
+    }
+}
+
+/// An OsStr as UTF-8, which hashes the same as the str.
+///
+/// Construct with try_from, which fails for non-UTF-8 values. There is no StableHash
+/// impl for OsStr or OsString, so that failure is always handled by the caller.
+pub struct AsOsStr<'a>(&'a str);
+
+impl<'a> TryFrom<&'a OsStr> for AsOsStr<'a> {
+    type Error = NonUtf8Error;
+
+    fn try_from(value: &'a OsStr) -> Result<Self, Self::Error> {
+        profile_method!(try_from);
+
+        value
+            .to_str()
+            .map(AsOsStr)
+            .ok_or_else(|| NonUtf8Error(value.to_owned()))
+    }
+}
+
+impl StableHash for AsOsStr<'_> {
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        self.0.stable_hash(field_address, state)
+    }
+}
+
 pub struct AsUnorderedSet<T>(pub T);
 
 impl<T, I> StableHash for AsUnorderedSet<T>
@@ -76,6 +283,29 @@ where
     }
 }
 
+/// Writes the members of a collection in iteration order, as a sequence the same as a Vec.
+/// This is an opt-in alternative to AsUnorderedSet for collections with a meaningful order,
+/// like BTreeMap. It is faster, but hashes to a different value than the default impl.
+pub struct AsSequence<T>(pub T);
+
+impl<T, I> StableHash for AsSequence<T>
+where
+    T: Copy + IntoIterator<Item = I>,
+    I: StableHash,
+{
+    fn stable_hash<H: StableHasher>(&self, field_address: H::Addr, state: &mut H) {
+        profile_method!(stable_hash);
+
+        let mut len = 0usize;
+        for member in self.0.into_iter() {
+            member.stable_hash(field_address.child(len as u64), state);
+            len += 1;
+        }
+        // See also 33a9b3bf-0d43-4fd0-a3ed-a77807505255
+        len.stable_hash(field_address, state);
+    }
+}
+
 pub(crate) fn generic_stable_hash<T: StableHash, H: StableHasher>(value: &T) -> H::Out {
     let mut hasher = H::new();
     value.stable_hash(FieldAddress::root(), &mut hasher);
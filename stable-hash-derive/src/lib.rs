@@ -0,0 +1,252 @@
+//! Provides #[derive(StableHash)] for the stable-hash crate. Use it through the
+//! "derive" feature of stable-hash, rather than depending on this crate directly.
+//!
+//! Each field must be given an explicit index with #[stable_hash(index = N)],
+//! which is the number passed to FieldAddress::child for that field. Unlike
+//! impl_stable_hash!, which numbers fields by declaration order, this means
+//! fields can be reordered without changing the hash. Indices must be unique
+//! within a struct, but need not be contiguous. To migrate from impl_stable_hash!
+//! without changing the hash, give each field its position in the old macro.
+//!
+//! Other field attributes:
+//! * skip: The field does not contribute to the hash, and needs no index.
+//! * with = path: The field is passed by reference to path, and the result is
+//!   hashed instead. For example, with = stable_hash::utils::AsBytes
+//!
+//! The type of each hashed field which uses a type parameter is required to
+//! implement StableHash. Skipped fields and fields using with add no bounds, so
+//! a type parameter only used by those does not need to implement StableHash.
+//! Add a where clause to the struct if the with wrapper needs one.
+//!
+//! ```
+//! use stable_hash::{utils::AsBytes, StableHash};
+//!
+//! #[derive(StableHash)]
+//! struct Block<T> {
+//!     #[stable_hash(index = 1)]
+//!     number: u64,
+//!     #[stable_hash(index = 0, with = AsBytes)]
+//!     hash: Vec<u8>,
+//!     #[stable_hash(index = 2)]
+//!     data: T,
+//!     #[stable_hash(skip)]
+//!     cached_size: usize,
+//! }
+//! ```
+//!
+//! Duplicate indices are a compile error:
+//!
+//! ```compile_fail
+//! use stable_hash::StableHash;
+//!
+//! #[derive(StableHash)]
+//! struct Duplicate {
+//!     #[stable_hash(index = 0)]
+//!     a: u32,
+//!     #[stable_hash(index = 0)]
+//!     b: u32,
+//! }
+//! ```
+//!
+//! As is a field without an index:
+//!
+//! ```compile_fail
+//! use stable_hash::StableHash;
+//!
+//! #[derive(StableHash)]
+//! struct Missing {
+//!     a: u32,
+//! }
+//! ```
+
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::quote;
+use std::collections::HashMap;
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Error, Fields, GenericParam,
+    Index, LitInt, Member, Path, Result,
+};
+
+#[proc_macro_derive(StableHash, attributes(stable_hash))]
+pub fn derive_stable_hash(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The parsed #[stable_hash(...)] attributes of a single field.
+#[derive(Default)]
+struct FieldAttrs {
+    index: Option<(u64, Span)>,
+    skip: bool,
+    with: Option<Path>,
+}
+
+impl FieldAttrs {
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = Self::default();
+        for attr in attrs {
+            if !attr.path().is_ident("stable_hash") {
+                continue;
+            }
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("index") {
+                    if result.index.is_some() {
+                        return Err(meta.error("duplicate stable_hash index"));
+                    }
+                    let lit: LitInt = meta.value()?.parse()?;
+                    result.index = Some((lit.base10_parse()?, lit.span()));
+                } else if meta.path.is_ident("skip") {
+                    result.skip = true;
+                } else if meta.path.is_ident("with") {
+                    if result.with.is_some() {
+                        return Err(meta.error("duplicate stable_hash with"));
+                    }
+                    result.with = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("expected index, skip, or with"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(result)
+    }
+}
+
+/// Whether the tokens mention any of the type parameters, for example Vec<T>.
+fn uses_type_param(tokens: TokenStream, type_params: &[Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => type_params.contains(&ident),
+        TokenTree::Group(group) => uses_type_param(group.stream(), type_params),
+        _ => false,
+    })
+}
+
+fn expand(mut input: DeriveInput) -> Result<TokenStream> {
+    let type_params: Vec<Ident> = input
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(param) => Some(param.ident.clone()),
+            _ => None,
+        })
+        .collect();
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "StableHash can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut writes = Vec::new();
+    let mut bounds: Vec<TokenStream> = Vec::new();
+    let mut indices: HashMap<u64, Span> = HashMap::new();
+    let mut errors: Option<Error> = None;
+    let mut push_error = |error: Error| match &mut errors {
+        Some(errors) => errors.combine(error),
+        None => errors = Some(error),
+    };
+
+    let members: Vec<Member> = match fields {
+        Fields::Named(named) => named
+            .named
+            .iter()
+            .map(|field| Member::Named(field.ident.clone().unwrap()))
+            .collect(),
+        Fields::Unnamed(unnamed) => (0..unnamed.unnamed.len())
+            .map(|i| Member::Unnamed(Index::from(i)))
+            .collect(),
+        Fields::Unit => Vec::new(),
+    };
+
+    for (field, member) in fields.iter().zip(members) {
+        let attrs = match FieldAttrs::parse(&field.attrs) {
+            Ok(attrs) => attrs,
+            Err(error) => {
+                push_error(error);
+                continue;
+            }
+        };
+        if attrs.skip {
+            if attrs.index.is_some() || attrs.with.is_some() {
+                push_error(Error::new_spanned(
+                    field,
+                    "a skipped field cannot have an index or with",
+                ));
+            }
+            continue;
+        }
+        let (index, span) = match attrs.index {
+            Some(index) => index,
+            None => {
+                push_error(Error::new_spanned(
+                    field,
+                    "missing #[stable_hash(index = N)] or #[stable_hash(skip)]",
+                ));
+                continue;
+            }
+        };
+        if let Some(first) = indices.insert(index, span) {
+            let mut error = Error::new(span, format!("duplicate stable_hash index {}", index));
+            error.combine(Error::new(first, "first used here"));
+            push_error(error);
+            continue;
+        }
+
+        let value = match &attrs.with {
+            Some(with) => quote! { &#with(&self.#member) },
+            None => {
+                let ty = &field.ty;
+                let tokens = quote! { #ty };
+                if uses_type_param(tokens.clone(), &type_params)
+                    && !bounds
+                        .iter()
+                        .any(|bound| bound.to_string() == tokens.to_string())
+                {
+                    bounds.push(tokens);
+                }
+                quote! { &self.#member }
+            }
+        };
+        writes.push(quote! {
+            ::stable_hash::StableHash::stable_hash(
+                #value,
+                ::stable_hash::FieldAddress::child(&field_address, #index),
+                state,
+            );
+        });
+    }
+
+    if let Some(errors) = errors {
+        return Err(errors);
+    }
+
+    let where_clause = input.generics.make_where_clause();
+    for ty in bounds {
+        where_clause
+            .predicates
+            .push(parse_quote! { #ty: ::stable_hash::StableHash });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::stable_hash::StableHash for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn stable_hash<__H: ::stable_hash::StableHasher>(
+                &self,
+                field_address: __H::Addr,
+                state: &mut __H,
+            ) {
+                #(#writes)*
+            }
+        }
+    })
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "alloy-primitives")]
+
+mod common;
+
+use alloy_primitives::{Address, B256, I256, U256};
+use stable_hash::utils::AsBytes;
+
+#[test]
+fn uint_same_as_int() {
+    equal!(300476818725221552349680556501826519020, "173097115007a0965e818effe3bc946da648604343807e529b1999b39a3a1e0b";
+        9u8,
+        U256::from(9),
+        ruint::Uint::<64, 1>::from(9),
+        ruint::Uint::<512, 8>::from(9)
+    );
+    equal!(316624692538722035069858420207567234408, "1c22c3e5312542ca82d3b63e21f2608864ac5a377d55f7c24d2c12eae2cd743e";
+        160141183460469231731687303715884105728u128,
+        U256::from(160141183460469231731687303715884105728u128)
+    );
+}
+
+#[test]
+fn signed_same_as_int() {
+    equal!(67048966086700017767258589930187130954, "867b0b908a1ee3f4b1473febd9a76e8950692e631b1c4e39b4c18d26606cba40";
+        -12i8,
+        I256::try_from(-12).unwrap()
+    );
+    not_equal!(I256::MIN, I256::MAX);
+}
+
+#[test]
+fn fixed_bytes_as_bytes() {
+    let bytes = [7u8; 32];
+    equal!(288321888162170399293519927497937836417, "97bf98a3f3ab150fa98640eebde88d5615518f58c9a618b67f95a3265e1cea7c";
+        B256::from(bytes),
+        AsBytes(&bytes)
+    );
+    not_equal!(Address::ZERO, Option::<Address>::None);
+}
+
+#[cfg(feature = "primitive-types")]
+#[test]
+fn same_as_primitive_types() {
+    let value = U256::from_str_radix("123456789abcdef0123456789abcdef0123456789", 16).unwrap();
+    let bytes: [u8; 32] = value.to_le_bytes();
+    assert_eq!(
+        common::fast_stable_hash(&value),
+        common::fast_stable_hash(&primitive_types::U256::from_little_endian(&bytes))
+    );
+    assert_eq!(
+        common::crypto_stable_hash_str(&B256::from(bytes)),
+        common::crypto_stable_hash_str(&primitive_types::H256::from(bytes))
+    );
+    assert_eq!(
+        common::crypto_stable_hash_str(&Address::repeat_byte(3)),
+        common::crypto_stable_hash_str(&primitive_types::H160::repeat_byte(3))
+    );
+}
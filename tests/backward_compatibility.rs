@@ -1,5 +1,9 @@
 use stable_hash::prelude::*;
 use stable_hash::utils::AsBytes;
+use std::borrow::Cow;
+use std::collections::{LinkedList, VecDeque};
+use std::rc::Rc;
+use std::sync::Arc;
 mod common;
 
 struct One<T0> {
@@ -107,3 +111,122 @@ fn numbers_through_vec() {
         vec![1u16, 2u16]
     );
 }
+
+#[test]
+fn array_same_as_vec() {
+    equal!(
+        99946908715907655724842348751554312075, "25dfaa9f92a3f2b05a1bdfbc66ec594c545dc39ebdb0e9ae769350ea1726e2b7";
+        [1u32, 2u32],
+        [1u16, 2u16],
+        vec![1u32, 2u32]
+    );
+}
+
+#[test]
+fn empty_array_is_default() {
+    let one = One { one: true };
+    let two = Two {
+        one: true,
+        two: [0u32; 0],
+    };
+    equal!(338065623630761276859032195206974584513, "db4657c873e33a60e581eb5458aba6c76f510e023872c76a3134608619342c59"; one, two);
+}
+
+#[test]
+fn array_last_default_does_not_collide() {
+    not_equal!([1u32, 2u32, 0u32], [1u32, 2u32]);
+}
+
+#[test]
+fn str_pointers_transparent() {
+    let mut string = String::from("abc");
+    equal!(
+        225126920276043178050448780660223346301, "fe0d5d84754701b6cbb91451f9d8c6e589219ad8c04096ba7e4fe2e303671d59";
+        "abc",
+        String::from("abc"),
+        Box::<str>::from("abc"),
+        Rc::<str>::from("abc"),
+        Arc::<str>::from("abc"),
+        Arc::new(String::from("abc")),
+        Cow::<str>::Borrowed("abc"),
+        Cow::<str>::Owned(String::from("abc")),
+        &mut string
+    );
+}
+
+#[test]
+fn slice_pointers_transparent() {
+    let mut array = [1u32, 2u32];
+    equal!(
+        99946908715907655724842348751554312075, "25dfaa9f92a3f2b05a1bdfbc66ec594c545dc39ebdb0e9ae769350ea1726e2b7";
+        Box::<[u32]>::from([1, 2]),
+        Rc::<[u32]>::from([1, 2]),
+        Arc::<[u32]>::from([1, 2]),
+        Box::new(vec![1u32, 2u32]),
+        Cow::<[u32]>::Borrowed(&[1, 2]),
+        Cow::<[u32]>::Owned(vec![1, 2]),
+        &mut array[..]
+    );
+}
+
+#[test]
+fn ok_default_ne() {
+    not_equal!(Result::<u32, u32>::Ok(0), Option::<u32>::None);
+}
+
+#[test]
+fn err_default_ne() {
+    not_equal!(Result::<u32, u32>::Err(0), Option::<u32>::None);
+}
+
+#[test]
+fn ok_ne_err() {
+    not_equal!(Result::<u32, u32>::Ok(0), Result::<u32, u32>::Err(0));
+    not_equal!(Result::<u32, u32>::Ok(1), Result::<u32, u32>::Err(1));
+}
+
+#[test]
+fn ok_ne_some() {
+    not_equal!(Result::<u32, u32>::Ok(1), Some(1u32));
+}
+
+#[test]
+fn result_in_struct() {
+    let one = Two {
+        one: Result::<&str, &str>::Ok("one"),
+        two: Result::<&str, &str>::Err(""),
+    };
+    let two = Two {
+        one: Result::<&str, &str>::Err("one"),
+        two: Result::<&str, &str>::Ok(""),
+    };
+    not_equal!(one, two);
+}
+
+#[test]
+fn unit_is_default() {
+    let one = One { one: true };
+    let two = Two { one: true, two: () };
+    let three = Two {
+        one: true,
+        two: std::marker::PhantomData::<u32>,
+    };
+    equal!(338065623630761276859032195206974584513, "db4657c873e33a60e581eb5458aba6c76f510e023872c76a3134608619342c59"; one, two, three);
+}
+
+#[test]
+fn sequences_same_as_vec() {
+    let mut deque = VecDeque::new();
+    deque.push_back(2u32);
+    deque.push_front(1u32);
+    equal!(
+        99946908715907655724842348751554312075, "25dfaa9f92a3f2b05a1bdfbc66ec594c545dc39ebdb0e9ae769350ea1726e2b7";
+        deque,
+        VecDeque::from([1u16, 2u16]),
+        LinkedList::from([1u32, 2u32])
+    );
+    not_equal!(
+        VecDeque::from([1u32, 2u32, 0u32]),
+        VecDeque::from([1u32, 2u32])
+    );
+}
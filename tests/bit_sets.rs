@@ -0,0 +1,79 @@
+#![cfg(any(feature = "bitvec", feature = "fixedbitset"))]
+
+mod common;
+
+#[cfg(feature = "bitvec")]
+use bitvec::prelude::*;
+#[cfg(feature = "fixedbitset")]
+use fixedbitset::FixedBitSet;
+
+#[cfg(feature = "fixedbitset")]
+fn fixed(len: usize, ones: &[usize]) -> FixedBitSet {
+    let mut set = FixedBitSet::with_capacity(len);
+    for &one in ones {
+        set.insert(one);
+    }
+    set
+}
+
+#[cfg(feature = "bitvec")]
+#[test]
+fn bitvec_same_as_int() {
+    equal!(300476818725221552349680556501826519020, "173097115007a0965e818effe3bc946da648604343807e529b1999b39a3a1e0b";
+        bitvec![1, 0, 0, 1],
+        bitvec![u32, Msb0; 1, 0, 0, 1],
+        bitarr![u16, Lsb0; 1, 0, 0, 1],
+        9u32
+    );
+}
+
+#[cfg(feature = "bitvec")]
+#[test]
+fn bitvec_trailing_zeros() {
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        BitVec::<usize, Lsb0>::new(),
+        bitvec![0; 100],
+        Option::<BitVec>::None
+    );
+    let mut long = bitvec![0; 1000];
+    long.set(3, true);
+    long.set(0, true);
+    equal!(300476818725221552349680556501826519020, "173097115007a0965e818effe3bc946da648604343807e529b1999b39a3a1e0b";
+        long,
+        long.clone().into_boxed_bitslice()
+    );
+    not_equal!(bitvec![1, 0, 0, 1], bitvec![0, 1, 0, 0, 1]);
+}
+
+#[cfg(feature = "fixedbitset")]
+#[test]
+fn fixedbitset_same_as_int() {
+    equal!(300476818725221552349680556501826519020, "173097115007a0965e818effe3bc946da648604343807e529b1999b39a3a1e0b";
+        fixed(4, &[0, 3]),
+        fixed(1000, &[0, 3]),
+        9u32
+    );
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        fixed(0, &[]),
+        fixed(100, &[])
+    );
+    not_equal!(fixed(100, &[0, 3]), fixed(100, &[0, 64]));
+}
+
+#[cfg(all(feature = "bitvec", feature = "fixedbitset"))]
+#[test]
+fn bitvec_same_as_fixedbitset() {
+    let mut bits = bitvec![u8, Msb0; 0; 200];
+    for index in [1, 63, 64, 65, 199] {
+        bits.set(index, true);
+    }
+    let set = fixed(200, &[1, 63, 64, 65, 199]);
+    assert_eq!(
+        common::fast_stable_hash(&bits),
+        common::fast_stable_hash(&set)
+    );
+    assert_eq!(
+        common::crypto_stable_hash_str(&bits),
+        common::crypto_stable_hash_str(&set)
+    );
+}
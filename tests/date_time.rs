@@ -0,0 +1,145 @@
+#![cfg(any(feature = "chrono", feature = "time"))]
+
+mod common;
+
+use stable_hash::utils::WithOffset;
+use std::time::{Duration, UNIX_EPOCH};
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_same_as_system_time() {
+    use chrono::{DateTime, Utc};
+    let duration = Duration::new(1_600_000_000, 123_456_789);
+    equal!(95959179847983699244629718784926432959, "2d76ee64fc9f371cde50acf55d83fe3cc1b96431b7aa8d738fc2de68d21c9c42";
+        UNIX_EPOCH + duration,
+        DateTime::<Utc>::from_timestamp(1_600_000_000, 123_456_789).unwrap(),
+        DateTime::<Utc>::from_timestamp(1_600_000_000, 123_456_789).unwrap().naive_utc(),
+        WithOffset(DateTime::<Utc>::from_timestamp(1_600_000_000, 123_456_789).unwrap())
+    );
+
+    let before = Duration::new(300_000_000, 250_000_000);
+    assert_eq!(
+        common::fast_stable_hash(&(UNIX_EPOCH - before)),
+        common::fast_stable_hash(
+            &DateTime::<Utc>::from_timestamp(-300_000_001, 750_000_000).unwrap()
+        )
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_offsets() {
+    use chrono::{DateTime, FixedOffset, Utc};
+    let utc = DateTime::<Utc>::from_timestamp(1_600_000_000, 0).unwrap();
+    let east = utc.with_timezone(&FixedOffset::east_opt(3600).unwrap());
+    let west = utc.with_timezone(&FixedOffset::west_opt(3600).unwrap());
+    equal!(189745210264711126507395424897511935042, "950874066656b40e7be6d32b5de8bee61d2ac67c4cff41d72b0ef6a5c5665584"; utc, east, west);
+    not_equal!(WithOffset(east), WithOffset(west));
+    not_equal!(WithOffset(east), utc);
+    not_equal!(east.naive_local(), utc.naive_utc());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_leap_second() {
+    use chrono::NaiveDate;
+    let last_day = NaiveDate::from_ymd_opt(2016, 12, 31).unwrap();
+    let next_day = NaiveDate::from_ymd_opt(2017, 1, 1).unwrap();
+    let leap = last_day
+        .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+        .unwrap();
+    let before = last_day.and_hms_nano_opt(23, 59, 59, 500_000_000).unwrap();
+    let after = next_day.and_hms_nano_opt(0, 0, 0, 500_000_000).unwrap();
+    not_equal!(leap, after);
+    not_equal!(leap, before);
+    not_equal!(leap.and_utc(), after.and_utc());
+    assert_eq!(
+        common::fast_stable_hash(&leap),
+        common::fast_stable_hash(&leap.and_utc())
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_date_is_midnight() {
+    use chrono::{NaiveDate, NaiveTime};
+    let date = NaiveDate::from_ymd_opt(1969, 7, 20).unwrap();
+    assert_eq!(
+        common::fast_stable_hash(&date),
+        common::fast_stable_hash(&date.and_time(NaiveTime::MIN).and_utc())
+    );
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(),
+        UNIX_EPOCH
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_same_as_system_time() {
+    use time::{Date, Month, OffsetDateTime, UtcOffset};
+    let duration = Duration::new(1_600_000_000, 123_456_789);
+    let utc = OffsetDateTime::from_unix_timestamp_nanos(1_600_000_000_123_456_789).unwrap();
+    equal!(95959179847983699244629718784926432959, "2d76ee64fc9f371cde50acf55d83fe3cc1b96431b7aa8d738fc2de68d21c9c42";
+        UNIX_EPOCH + duration,
+        utc,
+        utc.to_offset(UtcOffset::from_whole_seconds(-7200).unwrap()),
+        WithOffset(utc)
+    );
+    let before = Duration::new(300_000_000, 250_000_000);
+    assert_eq!(
+        common::fast_stable_hash(&(UNIX_EPOCH - before)),
+        common::fast_stable_hash(
+            &OffsetDateTime::from_unix_timestamp_nanos(-300_000_000_250_000_000).unwrap()
+        )
+    );
+    let date = Date::from_calendar_date(1969, Month::July, 20).unwrap();
+    assert_eq!(
+        common::fast_stable_hash(&date),
+        common::fast_stable_hash(&date.midnight().assume_utc())
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_offsets() {
+    use time::{OffsetDateTime, UtcOffset};
+    let utc = OffsetDateTime::from_unix_timestamp(1_600_000_000).unwrap();
+    let east = utc.to_offset(UtcOffset::from_whole_seconds(3600).unwrap());
+    let west = utc.to_offset(UtcOffset::from_whole_seconds(-3600).unwrap());
+    not_equal!(WithOffset(east), WithOffset(west));
+    not_equal!(WithOffset(east), utc);
+    assert_eq!(
+        common::fast_stable_hash(&east),
+        common::fast_stable_hash(&west)
+    );
+}
+
+#[cfg(all(feature = "chrono", feature = "time"))]
+#[test]
+fn chrono_same_as_time() {
+    use chrono::{DateTime, FixedOffset};
+    use time::{OffsetDateTime, UtcOffset};
+    let offset = 5 * 3600 + 30 * 60;
+    for nanos in [
+        0i64,
+        1,
+        -1,
+        1_600_000_000_123_456_789,
+        -5_000_000_000_000_000_000,
+    ] {
+        let chrono = DateTime::from_timestamp_nanos(nanos)
+            .with_timezone(&FixedOffset::east_opt(offset).unwrap());
+        let time = OffsetDateTime::from_unix_timestamp_nanos(nanos as i128)
+            .unwrap()
+            .to_offset(UtcOffset::from_whole_seconds(offset).unwrap());
+        assert_eq!(
+            common::crypto_stable_hash_str(&chrono),
+            common::crypto_stable_hash_str(&time)
+        );
+        assert_eq!(
+            common::crypto_stable_hash_str(&WithOffset(chrono)),
+            common::crypto_stable_hash_str(&WithOffset(time))
+        );
+    }
+}
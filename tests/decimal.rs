@@ -0,0 +1,138 @@
+#![cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+
+mod common;
+
+use stable_hash::utils::AsNumber;
+
+#[cfg(feature = "bigdecimal")]
+fn big(s: &str) -> bigdecimal::BigDecimal {
+    s.parse().unwrap()
+}
+
+#[cfg(feature = "rust_decimal")]
+fn dec(s: &str) -> rust_decimal::Decimal {
+    s.parse().unwrap()
+}
+
+#[cfg(feature = "bigdecimal")]
+#[test]
+fn big_decimal_normalized() {
+    equal!(288631778379196670628435548141258048327, "0d420aff0123a84acbecd600720812201fef6f36a097e5cfb4ce7adc8349b688";
+        big("1.5"),
+        big("1.50"),
+        big("001.500000"),
+        AsNumber(1.5f64)
+    );
+    equal!(300476818725221552349680556501826519020, "173097115007a0965e818effe3bc946da648604343807e529b1999b39a3a1e0b";
+        9u8,
+        big("9"),
+        big("9.000")
+    );
+    equal!(67048966086700017767258589930187130954, "867b0b908a1ee3f4b1473febd9a76e8950692e631b1c4e39b4c18d26606cba40";
+        -12i8,
+        big("-12.0")
+    );
+    equal!(180285864497012079198508575237188824476, "ecd99d9a6cd8c8e5cc414712269321c025af8c52db2042d364f32f67ba320afa";
+        90u8,
+        big("9E+1")
+    );
+    not_equal!(big("1.5"), big("-1.5"));
+    not_equal!(big("1.5"), big("15"));
+    not_equal!(big("1.5"), big("0.15"));
+}
+
+#[cfg(feature = "bigdecimal")]
+#[test]
+fn big_decimal_zero_is_default() {
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        big("0"),
+        big("0.000"),
+        big("-0.0"),
+        Option::<bigdecimal::BigDecimal>::None
+    );
+}
+
+/// These are short strings, so hashing them must be cheap regardless of the exponent.
+#[cfg(feature = "bigdecimal")]
+#[test]
+fn big_decimal_extreme_exponents() {
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        big("0e-100000000"),
+        big("0e-9223372036854775807"),
+        big("0e9223372036854775807")
+    );
+    equal!(63834017804212420631610856909933582084, "5f168c0c7aa31825795bed346160e069e1b99ec559a0951b423cf540d00c7f5f";
+        big("1e9223372036854775807"),
+        big("10e9223372036854775806")
+    );
+    not_equal!(big("1e9223372036854775807"), big("1e9223372036854775806"));
+    not_equal!(big("1e-9223372036854775807"), big("1e-9223372036854775806"));
+    not_equal!(big("1e10000000"), big("-1e10000000"));
+}
+
+/// Equal values hash the same, however the scale is given.
+#[cfg(feature = "bigdecimal")]
+#[test]
+fn big_decimal_large_integer() {
+    use bigdecimal::{num_bigint::BigInt, BigDecimal};
+
+    let expanded = BigDecimal::new(BigInt::from(10).pow(5000), 0);
+    assert_eq!(big("1e5000"), expanded);
+    equal!(199396217185030403774149416043066865588, "0759c81dea2e4eaea00148ab2a9d321cb8d68dd8761d86d44cd1c7d03a5016f3";
+        big("1e5000"),
+        expanded
+    );
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn decimal_normalized() {
+    equal!(288631778379196670628435548141258048327, "0d420aff0123a84acbecd600720812201fef6f36a097e5cfb4ce7adc8349b688";
+        dec("1.5"),
+        dec("1.50"),
+        AsNumber(1.5f64)
+    );
+    equal!(300476818725221552349680556501826519020, "173097115007a0965e818effe3bc946da648604343807e529b1999b39a3a1e0b";
+        9u8,
+        dec("9.000")
+    );
+    equal!(67048966086700017767258589930187130954, "867b0b908a1ee3f4b1473febd9a76e8950692e631b1c4e39b4c18d26606cba40";
+        -12i8,
+        dec("-12.0")
+    );
+    not_equal!(dec("1.5"), dec("-1.5"));
+    not_equal!(dec("0.1"), dec("0.01"));
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn decimal_zero_is_default() {
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        dec("0"),
+        dec("0.000"),
+        dec("-0.00"),
+        rust_decimal::Decimal::ZERO
+    );
+}
+
+#[cfg(all(feature = "bigdecimal", feature = "rust_decimal"))]
+#[test]
+fn big_decimal_same_as_decimal() {
+    for s in [
+        "1.5",
+        "-0.001",
+        "79228162514264337593543950335",
+        "-7.9228162514264337593543950335",
+        "100",
+        "0.10",
+    ] {
+        assert_eq!(
+            common::fast_stable_hash(&big(s)),
+            common::fast_stable_hash(&dec(s))
+        );
+        assert_eq!(
+            common::crypto_stable_hash_str(&big(s)),
+            common::crypto_stable_hash_str(&dec(s))
+        );
+    }
+}
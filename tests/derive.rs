@@ -0,0 +1,155 @@
+#![cfg(feature = "derive")]
+
+mod common;
+
+use stable_hash::utils::AsBytes;
+use stable_hash::{impl_stable_hash, StableHash};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+#[derive(StableHash)]
+struct Declared {
+    #[stable_hash(index = 0)]
+    a: u32,
+    #[stable_hash(index = 1)]
+    b: String,
+}
+
+#[derive(StableHash)]
+struct Reordered {
+    #[stable_hash(index = 1)]
+    b: String,
+    #[stable_hash(index = 0)]
+    a: u32,
+}
+
+struct Macro {
+    a: u32,
+    b: String,
+}
+
+impl_stable_hash!(Macro { a, b });
+
+#[test]
+fn reorder_fields() {
+    equal!(95313193802198411058012177217693478373, "3f55ed97f39dd88ee5e9903dc3972b3c8711e9867f2b5c3d19626164c97e6ece";
+        Declared { a: 9, b: "abc".to_owned() },
+        Reordered { b: "abc".to_owned(), a: 9 },
+        Macro { a: 9, b: "abc".to_owned() }
+    );
+}
+
+#[derive(StableHash)]
+struct Skipped {
+    #[stable_hash(index = 0)]
+    a: u32,
+    #[stable_hash(skip)]
+    _cache: Vec<u8>,
+    #[stable_hash(index = 1)]
+    b: String,
+}
+
+#[test]
+fn skip() {
+    equal!(95313193802198411058012177217693478373, "3f55ed97f39dd88ee5e9903dc3972b3c8711e9867f2b5c3d19626164c97e6ece";
+        Skipped { a: 9, _cache: vec![1, 2], b: "abc".to_owned() },
+        Skipped { a: 9, _cache: vec![], b: "abc".to_owned() }
+    );
+}
+
+#[derive(StableHash)]
+struct Sparse {
+    #[stable_hash(index = 7)]
+    a: u32,
+}
+
+#[test]
+fn index_is_child() {
+    not_equal!(
+        Sparse { a: 9 },
+        Declared {
+            a: 9,
+            b: String::new()
+        }
+    );
+}
+
+#[derive(StableHash)]
+struct WithBytes {
+    #[stable_hash(index = 0, with = AsBytes)]
+    bytes: Vec<u8>,
+}
+
+struct MacroBytes {
+    bytes: Vec<u8>,
+}
+
+impl_stable_hash!(MacroBytes { bytes: AsBytes });
+
+#[test]
+fn with() {
+    equal!(60684104414326725392718373442963619122, "42dbb74fa9913a98e284de18d625f4fa54a268bc768d69409a28c5ffee0532aa";
+        WithBytes { bytes: vec![1, 2, 3] },
+        MacroBytes { bytes: vec![1, 2, 3] }
+    );
+}
+
+#[derive(StableHash)]
+struct Generic<'a, T, const N: usize>
+where
+    T: Debug,
+{
+    #[stable_hash(index = 0)]
+    a: &'a T,
+    #[stable_hash(index = 1)]
+    b: [T; N],
+}
+
+#[allow(dead_code)]
+#[derive(StableHash)]
+struct Tuple(
+    #[stable_hash(index = 0)] u32,
+    #[stable_hash(skip)] bool,
+    #[stable_hash(index = 1)] String,
+);
+
+struct NotHashable;
+
+#[derive(StableHash)]
+struct SkippedGeneric<T, U> {
+    #[stable_hash(index = 0)]
+    a: u32,
+    #[stable_hash(skip)]
+    _skipped: T,
+    #[stable_hash(skip)]
+    _phantom: PhantomData<U>,
+}
+
+#[test]
+fn skipped_generic_needs_no_bound() {
+    equal!(223305313693080130523755331637514648741, "796d614fe8e54036ee76c303a00cbdb047b9f4002db0b0ba0fb797289a7cb835";
+        SkippedGeneric { a: 9, _skipped: NotHashable, _phantom: PhantomData::<NotHashable> },
+        Declared { a: 9, b: String::new() }
+    );
+}
+
+#[derive(StableHash)]
+struct Unit;
+
+#[test]
+fn generics_and_tuples() {
+    equal!(223305313693080130523755331637514648741, "796d614fe8e54036ee76c303a00cbdb047b9f4002db0b0ba0fb797289a7cb835";
+        Generic { a: &9u32, b: [0u32; 0] },
+        Tuple(9, true, String::new()),
+        Declared { a: 9, b: String::new() }
+    );
+}
+
+#[test]
+fn empty_is_default() {
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        Unit,
+        Declared { a: 0, b: String::new() },
+        Option::<u32>::None
+    );
+}
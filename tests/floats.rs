@@ -0,0 +1,54 @@
+mod common;
+
+#[test]
+fn f32_same_as_f64() {
+    for value in [
+        1.0f32,
+        -1.5,
+        0.1,
+        f32::MAX,
+        f32::MIN_POSITIVE,
+        f32::from_bits(1),
+    ] {
+        assert_eq!(
+            common::fast_stable_hash(&value),
+            common::fast_stable_hash(&(value as f64))
+        );
+        assert_eq!(
+            common::crypto_stable_hash_str(&value),
+            common::crypto_stable_hash_str(&(value as f64))
+        );
+    }
+}
+
+#[test]
+fn zero_is_default() {
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b"; 0.0f32, -0.0f32, 0.0f64, -0.0f64, Option::<f64>::None);
+}
+
+#[test]
+fn numbers() {
+    equal!(232837036531045221522761394562804987915, "001a64480afdb429eeca85a293de735110884e56136a9b4f5cb49dc1e4e24550"; 0.1f32, 0.1f32 as f64);
+    equal!(244349587725466236005511667773780614424, "45581e617d1b1246e9a85ec507898868369a6a3ce6a7813fb68359bd61b6ba59"; -2.5f32, -2.5f64);
+    equal!(135263302447443856369810803691068577694, "044100289e98a89ed394a64fec6960dbab147ca5b6560883c9ce5d65cd69bf51"; f32::INFINITY, f64::INFINITY);
+    equal!(217998006954740137880519185293230341169, "4e4a546ea097d350990f263ab06689e5556a87d75bd9bb5351c64954564759ee"; f32::NEG_INFINITY, f64::NEG_INFINITY);
+    equal!(11352587151805930934672686630111428748, "5e01a81eb252f2a2e7b8c628934a51d57b6ed47cf656d7f9cac597c98746ae64"; f32::NAN, f64::NAN, -f64::NAN);
+}
+
+#[test]
+fn special_values_ne() {
+    not_equal!(f64::INFINITY, f64::NEG_INFINITY);
+    not_equal!(f64::INFINITY, f64::NAN);
+    not_equal!(f64::NEG_INFINITY, f64::NAN);
+    not_equal!(f64::MAX, f64::INFINITY);
+    not_equal!(f64::from_bits(1), 0.0f64);
+    not_equal!(1.0f64, -1.0f64);
+    not_equal!(1.0f64, 2.0f64);
+    not_equal!(1.0f64, 1.5f64);
+    not_equal!(0.1f32 as f64, 0.1f64);
+}
+
+#[test]
+fn float_ne_int() {
+    not_equal!(1.0f64, 1u32);
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "half")]
+
+mod common;
+
+use half::{bf16, f16};
+use stable_hash::utils::AsNumber;
+
+/// Every f16 and bf16 is exactly representable as an f32, so all bit patterns
+/// are checked against the wider types.
+#[test]
+fn all_f16_same_as_f32_and_f64() {
+    for bits in 0..=u16::MAX {
+        let value = f16::from_bits(bits);
+        let hash = common::fast_stable_hash(&value);
+        assert_eq!(hash, common::fast_stable_hash(&value.to_f32()));
+        assert_eq!(hash, common::fast_stable_hash(&value.to_f64()));
+        assert_eq!(
+            common::fast_stable_hash(&AsNumber(value)),
+            common::fast_stable_hash(&AsNumber(value.to_f64()))
+        );
+    }
+}
+
+#[test]
+fn all_bf16_same_as_f32_and_f64() {
+    for bits in 0..=u16::MAX {
+        let value = bf16::from_bits(bits);
+        let hash = common::fast_stable_hash(&value);
+        assert_eq!(hash, common::fast_stable_hash(&value.to_f32()));
+        assert_eq!(hash, common::fast_stable_hash(&value.to_f64()));
+        assert_eq!(
+            common::fast_stable_hash(&AsNumber(value)),
+            common::fast_stable_hash(&AsNumber(value.to_f64()))
+        );
+    }
+}
+
+#[test]
+fn zero_is_default() {
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        f16::ZERO,
+        f16::NEG_ZERO,
+        bf16::ZERO,
+        bf16::NEG_ZERO,
+        0.0f32
+    );
+}
+
+#[test]
+fn crypto_same_as_f32() {
+    for value in [1.5f32, -0.1, 65504.0, f32::INFINITY, f32::NAN] {
+        assert_eq!(
+            common::crypto_stable_hash_str(&f16::from_f32(value).to_f32()),
+            common::crypto_stable_hash_str(&f16::from_f32(value))
+        );
+        assert_eq!(
+            common::crypto_stable_hash_str(&bf16::from_f32(value).to_f32()),
+            common::crypto_stable_hash_str(&bf16::from_f32(value))
+        );
+    }
+}
+
+#[test]
+fn integral_as_number_same_as_int() {
+    equal!(300476818725221552349680556501826519020, "173097115007a0965e818effe3bc946da648604343807e529b1999b39a3a1e0b";
+        AsNumber(f16::from_f32(9.0)),
+        AsNumber(bf16::from_f32(9.0)),
+        9u32
+    );
+}
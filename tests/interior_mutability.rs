@@ -0,0 +1,72 @@
+mod common;
+
+use std::cell::{Cell, OnceCell, RefCell};
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+
+#[test]
+fn transparent() {
+    equal!(99946908715907655724842348751554312075, "25dfaa9f92a3f2b05a1bdfbc66ec594c545dc39ebdb0e9ae769350ea1726e2b7";
+        vec![1u32, 2u32],
+        RefCell::new(vec![1u32, 2u32]),
+        Mutex::new(vec![1u32, 2u32]),
+        RwLock::new(vec![1u32, 2u32]),
+        Arc::new(RwLock::new(vec![1u32, 2u32])),
+        RefCell::new(vec![Cell::new(1u32), Cell::new(2u32)])
+    );
+}
+
+#[test]
+fn once_cell_same_as_option() {
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        OnceCell::<u32>::new(),
+        OnceLock::<u32>::new(),
+        Option::<u32>::None
+    );
+
+    let cell = OnceCell::new();
+    cell.set(0u32).unwrap();
+    let lock = OnceLock::new();
+    lock.set(0u32).unwrap();
+    assert_eq!(
+        common::fast_stable_hash(&cell),
+        common::fast_stable_hash(&Some(0u32))
+    );
+    assert_eq!(
+        common::crypto_stable_hash_str(&lock),
+        common::crypto_stable_hash_str(&Some(0u32))
+    );
+    not_equal!(cell, OnceCell::<u32>::new());
+}
+
+#[test]
+fn current_contents() {
+    let cell = RefCell::new(1u32);
+    let before = common::fast_stable_hash(&cell);
+    *cell.borrow_mut() = 2;
+    assert_ne!(before, common::fast_stable_hash(&cell));
+    assert_eq!(
+        common::fast_stable_hash(&2u32),
+        common::fast_stable_hash(&cell)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cannot hash a poisoned Mutex")]
+fn poisoned_mutex_panics() {
+    let mutex = Arc::new(Mutex::new(1u32));
+    let poison = mutex.clone();
+    let _ = std::thread::spawn(move || {
+        let _guard = poison.lock().unwrap();
+        panic!("poison");
+    })
+    .join();
+    common::fast_stable_hash(&mutex);
+}
+
+#[test]
+#[should_panic(expected = "already mutably borrowed")]
+fn mutably_borrowed_ref_cell_panics() {
+    let cell = RefCell::new(1u32);
+    let _borrow = cell.borrow_mut();
+    common::fast_stable_hash(&cell);
+}
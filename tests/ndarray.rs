@@ -0,0 +1,46 @@
+#![cfg(feature = "ndarray")]
+
+mod common;
+
+use ndarray::{arr1, arr2, Array2, ShapeBuilder};
+
+#[test]
+fn shape_and_elements() {
+    equal!(16220772008496681925194205205044380234, "a2245c92a83f571ee21e89b30bdcca1c13996dce8e7ee8143508d36457f70c77";
+        arr2(&[[1u32, 2, 3], [4, 5, 6]])
+    );
+}
+
+#[test]
+fn layout_independent() {
+    let owned = arr2(&[[1u32, 4], [2, 5], [3, 6]]);
+    let transposed = arr2(&[[1u32, 2, 3], [4, 5, 6]]);
+    assert_eq!(
+        common::fast_stable_hash(&owned),
+        common::fast_stable_hash(&transposed.t())
+    );
+    assert_eq!(
+        common::crypto_stable_hash_str(&owned),
+        common::crypto_stable_hash_str(&transposed.t())
+    );
+
+    let column_major = Array2::from_shape_vec((3, 2).f(), vec![1u32, 2, 3, 4, 5, 6]).unwrap();
+    assert_eq!(
+        common::fast_stable_hash(&owned),
+        common::fast_stable_hash(&column_major)
+    );
+    assert_eq!(
+        common::fast_stable_hash(&owned),
+        common::fast_stable_hash(&owned.view())
+    );
+}
+
+#[test]
+fn shape_contributes() {
+    not_equal!(
+        arr2(&[[1u32, 2, 3], [4, 5, 6]]),
+        arr2(&[[1u32, 2], [3, 4], [5, 6]])
+    );
+    not_equal!(arr1(&[1u32, 2, 3, 4]), arr2(&[[1u32, 2], [3, 4]]));
+    not_equal!(arr1(&[1u32, 2]), vec![1u32, 2]);
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6};
+
+#[test]
+fn ip_addr() {
+    equal!(
+        49488485216258286648758401011642981386, "3101283716e7fbe35a5d560c87df35c721f643513ed3ef31f11ed5045c1e6813";
+        IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)),
+        IpAddr::from([192, 168, 0, 1])
+    );
+    not_equal!(Ipv4Addr::new(1, 2, 3, 4), Ipv4Addr::new(4, 3, 2, 1));
+}
+
+#[test]
+fn v4_ne_mapped_v6() {
+    let v4 = Ipv4Addr::new(1, 2, 3, 4);
+    not_equal!(v4, v4.to_ipv6_mapped());
+    not_equal!(IpAddr::V4(v4), IpAddr::V6(v4.to_ipv6_mapped()));
+    not_equal!(IpAddr::V4(v4), IpAddr::V6(v4.to_ipv6_compatible()));
+}
+
+#[test]
+fn unspecified_ne() {
+    not_equal!(
+        IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+        IpAddr::V6(Ipv6Addr::UNSPECIFIED)
+    );
+    not_equal!(IpAddr::V4(Ipv4Addr::UNSPECIFIED), Option::<IpAddr>::None);
+}
+
+#[test]
+fn socket_addr() {
+    let ip = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    not_equal!(SocketAddr::new(ip, 8000), SocketAddr::new(ip, 8001));
+    not_equal!(SocketAddr::new(ip, 0), ip);
+    not_equal!(
+        SocketAddr::new(ip, 8000),
+        SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), 8000)
+    );
+    not_equal!(
+        SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8000, 0, 0),
+        SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8000, 0, 1)
+    );
+}
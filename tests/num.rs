@@ -0,0 +1,62 @@
+#![cfg(any(feature = "num-bigint", feature = "num-rational"))]
+
+mod common;
+
+#[cfg(feature = "num-bigint")]
+#[test]
+fn big_int_same_as_int() {
+    use num_bigint::{BigInt, BigUint};
+    equal!(300476818725221552349680556501826519020, "173097115007a0965e818effe3bc946da648604343807e529b1999b39a3a1e0b";
+        9u8,
+        BigUint::from(9u8),
+        BigInt::from(9)
+    );
+    equal!(164532978612348024195114867208653811138, "8aaff1a84ed29f58b02ee2d09605bfddfb8c3003bf7bb2d0c71265ceaa3457c2";
+        i64::MIN,
+        BigInt::from(i64::MIN)
+    );
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        BigUint::from(0u8),
+        BigInt::from(0)
+    );
+    not_equal!(BigInt::from(u128::MAX) + 1, BigInt::from(u128::MAX));
+}
+
+#[cfg(feature = "num-rational")]
+#[test]
+fn ratio_canonical() {
+    use num_rational::Ratio;
+    equal!(287899678239342090505087862041295929675, "03c07dcfa84de72fff077b95d7963053045e0898c20242da6e0f6d28bc650d32";
+        Ratio::new_raw(2i32, 4i32),
+        Ratio::new_raw(-1i64, -2i64),
+        Ratio::new(1u8, 2u8)
+    );
+    not_equal!(Ratio::new(1, 2), Ratio::new(-1, 2));
+    not_equal!(Ratio::new(1, 2), Ratio::new(2, 1));
+    not_equal!(Ratio::new(1, 2), Ratio::new(1, 3));
+}
+
+#[cfg(feature = "num-rational")]
+#[test]
+fn integral_ratio_same_as_int() {
+    use num_rational::Ratio;
+    equal!(67048966086700017767258589930187130954, "867b0b908a1ee3f4b1473febd9a76e8950692e631b1c4e39b4c18d26606cba40";
+        -12i8,
+        Ratio::new(-24, 2),
+        Ratio::new_raw(12, -1)
+    );
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        Ratio::new(0, 5)
+    );
+}
+
+#[cfg(all(feature = "num-bigint", feature = "num-rational"))]
+#[test]
+fn big_rational() {
+    use num_bigint::BigInt;
+    use num_rational::BigRational;
+    assert_eq!(
+        common::fast_stable_hash(&BigRational::new(BigInt::from(6), BigInt::from(4))),
+        common::fast_stable_hash(&num_rational::Ratio::new(3i32, 2i32))
+    );
+}
@@ -1,5 +1,6 @@
 #[macro_use]
 mod common;
+use stable_hash::utils::{AsDecimal, AsNumber};
 use std::mem::size_of;
 
 macro_rules! nums_equal {
@@ -62,3 +63,161 @@ fn down_to_i16() {
 fn down_to_i8() {
     nums_equal!(-12i8, 67048966086700017767258589930187130954, "867b0b908a1ee3f4b1473febd9a76e8950692e631b1c4e39b4c18d26606cba40"; i8, i16, i32, i64, i128);
 }
+
+#[test]
+fn integral_as_number() {
+    equal!(300476818725221552349680556501826519020, "173097115007a0965e818effe3bc946da648604343807e529b1999b39a3a1e0b";
+        AsNumber(9.0f32),
+        AsNumber(9.0f64),
+        AsDecimal { is_negative: false, little_endian: &[9], scale: 0 },
+        AsDecimal { is_negative: false, little_endian: &900u16.to_le_bytes(), scale: 2 }
+    );
+    equal!(67048966086700017767258589930187130954, "867b0b908a1ee3f4b1473febd9a76e8950692e631b1c4e39b4c18d26606cba40";
+        AsNumber(-12.0f64),
+        AsDecimal { is_negative: true, little_endian: &[120], scale: 1 }
+    );
+}
+
+#[test]
+fn negative_scale() {
+    equal!(180285864497012079198508575237188824476, "ecd99d9a6cd8c8e5cc414712269321c025af8c52db2042d364f32f67ba320afa";
+        90u8,
+        AsNumber(90.0f64),
+        AsDecimal { is_negative: false, little_endian: &[9], scale: -1 }
+    );
+}
+
+#[test]
+fn large_negative_scale() {
+    use stable_hash::utils::MAX_DECIMAL_EXPANSION;
+
+    let limit = MAX_DECIMAL_EXPANSION as i64;
+    let pow = |exponent: i64| ibig::UBig::from(10u8).pow(exponent as usize);
+    equal!(231080573659867901859568096380213152364, "b18594940d8d113d504dd40ee552a776ea4ecc8aa24499f85f2ee81c1ec05487";
+        AsDecimal { is_negative: false, little_endian: &[1], scale: -limit },
+        AsDecimal { is_negative: false, little_endian: &[100], scale: 2 - limit },
+        pow(limit)
+    );
+    // Past the limit the value is not expanded, so it no longer hashes as an integer.
+    equal!(178939859290269789907633814000860123464, "10214ead73b2c161f99fd624e2f36f32f3b7506ec9cec8107f4b67e98b2a9f81";
+        AsDecimal { is_negative: false, little_endian: &[1], scale: -limit - 1 },
+        AsDecimal { is_negative: false, little_endian: &[10], scale: -limit },
+        AsDecimal { is_negative: false, little_endian: &pow(limit + 1).to_le_bytes(), scale: 0 },
+        AsDecimal { is_negative: false, little_endian: &pow(limit + 3).to_le_bytes(), scale: 2 }
+    );
+    not_equal!(
+        AsDecimal {
+            is_negative: false,
+            little_endian: &[1],
+            scale: -limit - 1
+        },
+        pow(limit + 1)
+    );
+    not_equal!(
+        AsDecimal {
+            is_negative: false,
+            little_endian: &[1],
+            scale: i64::MIN
+        },
+        AsDecimal {
+            is_negative: false,
+            little_endian: &[1],
+            scale: i64::MAX
+        }
+    );
+}
+
+#[test]
+fn fraction_as_number() {
+    equal!(330514188156790647941299245700916547358, "a0282279eba7282f58574d29ace639dfce86aa99049a6605b932edae4175b63c";
+        AsNumber(0.5f32),
+        AsNumber(0.5f64),
+        AsDecimal { is_negative: false, little_endian: &[5], scale: 1 },
+        AsDecimal { is_negative: false, little_endian: &[50], scale: 2 }
+    );
+    not_equal!(AsNumber(0.5f64), AsNumber(-0.5f64));
+    not_equal!(AsNumber(1.5f64), 15u8);
+    not_equal!(AsNumber(1.5f64), AsNumber(0.15f64));
+    not_equal!(AsNumber(f64::NAN), AsNumber(f64::INFINITY));
+    not_equal!(AsNumber(f64::NEG_INFINITY), AsNumber(f64::INFINITY));
+}
+
+#[test]
+fn zero_as_number() {
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        0u8,
+        AsNumber(-0.0f64),
+        AsDecimal { is_negative: false, little_endian: &[0], scale: 3 },
+        AsDecimal { is_negative: false, little_endian: &[0], scale: i64::MAX },
+        AsDecimal { is_negative: false, little_endian: &[0], scale: i64::MIN }
+    );
+}
+
+#[test]
+fn non_zero_same_as_int() {
+    use std::num::{NonZeroI8, NonZeroU64, NonZeroU8};
+    equal!(300476818725221552349680556501826519020, "173097115007a0965e818effe3bc946da648604343807e529b1999b39a3a1e0b";
+        9u8,
+        NonZeroU8::new(9).unwrap(),
+        NonZeroU64::new(9).unwrap()
+    );
+    equal!(67048966086700017767258589930187130954, "867b0b908a1ee3f4b1473febd9a76e8950692e631b1c4e39b4c18d26606cba40";
+        -12i8,
+        NonZeroI8::new(-12).unwrap()
+    );
+}
+
+#[test]
+fn wrappers_same_as_int() {
+    use std::cmp::Reverse;
+    use std::num::{Saturating, Wrapping};
+    equal!(300476818725221552349680556501826519020, "173097115007a0965e818effe3bc946da648604343807e529b1999b39a3a1e0b";
+        Wrapping(9u8),
+        Saturating(9u32),
+        Reverse(9i64)
+    );
+}
+
+#[test]
+fn char_same_as_u32() {
+    equal!(300476818725221552349680556501826519020, "173097115007a0965e818effe3bc946da648604343807e529b1999b39a3a1e0b";
+        '\t',
+        9u32
+    );
+    not_equal!('a', 'b');
+    not_equal!('\u{10FFFF}', '\u{FFFF}');
+}
+
+#[test]
+fn ordering_same_as_i8() {
+    use std::cmp::Ordering;
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        Ordering::Equal,
+        0i8
+    );
+    equal!(180758524059554348236761602192907109688, "3b3857570435fc1783744f7ab985dacfbd24f36a4ef642e6861cf7b65c6305cb"; Ordering::Less, -1i8);
+    equal!(135263302447443856369810803691068577694, "044100289e98a89ed394a64fec6960dbab147ca5b6560883c9ce5d65cd69bf51"; Ordering::Greater, 1i8);
+}
+
+#[test]
+fn big_same_as_int() {
+    use ibig::{IBig, UBig};
+    equal!(300476818725221552349680556501826519020, "173097115007a0965e818effe3bc946da648604343807e529b1999b39a3a1e0b";
+        9u8,
+        UBig::from(9u8),
+        IBig::from(9)
+    );
+    equal!(164532978612348024195114867208653811138, "8aaff1a84ed29f58b02ee2d09605bfddfb8c3003bf7bb2d0c71265ceaa3457c2";
+        i64::MIN,
+        IBig::from(i64::MIN)
+    );
+    equal!(316624692538722035069858420207567234408, "1c22c3e5312542ca82d3b63e21f2608864ac5a377d55f7c24d2c12eae2cd743e";
+        160141183460469231731687303715884105728u128,
+        UBig::from(160141183460469231731687303715884105728u128)
+    );
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        UBig::from(0u8),
+        IBig::from(0)
+    );
+    not_equal!(UBig::from(u128::MAX) + 1u8, UBig::from(u128::MAX));
+}
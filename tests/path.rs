@@ -0,0 +1,79 @@
+mod common;
+
+use stable_hash::utils::{AsOsStr, AsPath, NonUtf8Error};
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+fn path(s: &str) -> AsPath<'_> {
+    AsPath::try_from(Path::new(s)).unwrap()
+}
+
+#[test]
+fn path_same_as_components() {
+    let path_buf = PathBuf::from("a/b");
+    equal!(203516092676089864476118623122043681982, "c03217ad0d899d287cd36ffe04333357f41c9d3ebb7d9f5ff4121b7db9c0572d";
+        path("a/b"),
+        path("a//b/"),
+        path("a/./b"),
+        AsPath::try_from(path_buf.as_path()).unwrap(),
+        vec!["a", "b"]
+    );
+}
+
+#[test]
+fn root_is_a_component() {
+    equal!(282661353906980602712870573227887827029, "0aa1b4b16472109d7113ef5bf59145518871cc899731828e2616d005c385e149";
+        path("/usr/lib"),
+        vec!["/", "usr", "lib"]
+    );
+    not_equal!(path("/usr/lib"), path("usr/lib"));
+    not_equal!(path("a/../b"), path("b"));
+}
+
+#[test]
+fn empty_is_default() {
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        path(""),
+        AsOsStr::try_from(OsStr::new("")).unwrap(),
+        Option::<u32>::None
+    );
+}
+
+#[test]
+fn os_str_same_as_str() {
+    let os_string = OsString::from("abc");
+    equal!(225126920276043178050448780660223346301, "fe0d5d84754701b6cbb91451f9d8c6e589219ad8c04096ba7e4fe2e303671d59";
+        AsOsStr::try_from(OsStr::new("abc")).unwrap(),
+        AsOsStr::try_from(os_string.as_os_str()).unwrap(),
+        "abc"
+    );
+}
+
+#[cfg(unix)]
+fn non_utf8() -> OsString {
+    use std::os::unix::ffi::OsStringExt;
+    OsString::from_vec(vec![b'a', 0xFF])
+}
+
+#[cfg(unix)]
+#[test]
+fn non_utf8_is_err() {
+    let value = non_utf8();
+    let path = PathBuf::from(&value);
+    assert_eq!(
+        AsPath::try_from(path.as_path()).err(),
+        Some(NonUtf8Error(value.clone()))
+    );
+    assert_eq!(
+        AsOsStr::try_from(value.as_os_str()).err(),
+        Some(NonUtf8Error(value.clone()))
+    );
+
+    fn try_hash(path: &Path) -> Result<u128, Box<dyn std::error::Error>> {
+        Ok(common::fast_stable_hash(&AsPath::try_from(path)?))
+    }
+    assert_eq!(
+        try_hash(&path).unwrap_err().to_string(),
+        "value is not valid UTF-8: \"a\\xFF\""
+    );
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "primitive-types")]
+
+mod common;
+
+use primitive_types::{H160, H256, U128, U256, U512};
+use stable_hash::utils::AsBytes;
+
+#[test]
+fn uint_same_as_int() {
+    equal!(300476818725221552349680556501826519020, "173097115007a0965e818effe3bc946da648604343807e529b1999b39a3a1e0b";
+        9u8,
+        U128::from(9),
+        U256::from(9),
+        U512::from(9)
+    );
+    equal!(316624692538722035069858420207567234408, "1c22c3e5312542ca82d3b63e21f2608864ac5a377d55f7c24d2c12eae2cd743e";
+        160141183460469231731687303715884105728u128,
+        U256::from(160141183460469231731687303715884105728u128)
+    );
+}
+
+#[test]
+fn uint_zero_is_default() {
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        U256::zero(),
+        Option::<U256>::None
+    );
+}
+
+#[test]
+fn uint_large_ne() {
+    not_equal!(U256::MAX, U256::MAX - 1);
+    not_equal!(U256::MAX, U512::from(U256::MAX) + 1);
+}
+
+#[test]
+fn hash_as_bytes() {
+    let bytes = [7u8; 32];
+    equal!(288321888162170399293519927497937836417, "97bf98a3f3ab150fa98640eebde88d5615518f58c9a618b67f95a3265e1cea7c";
+        H256::from(bytes),
+        AsBytes(&bytes)
+    );
+    not_equal!(H160::zero(), Option::<H160>::None);
+    not_equal!(H160::repeat_byte(1), H256::repeat_byte(1));
+}
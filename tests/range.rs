@@ -0,0 +1,42 @@
+mod common;
+
+use std::ops::Bound;
+
+#[test]
+fn empty_range_is_default() {
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        0u64..0u64,
+        Bound::<u64>::Unbounded,
+        Option::<u64>::None
+    );
+}
+
+#[test]
+fn range_ne_inclusive() {
+    not_equal!(0u64..0u64, 0u64..=0u64);
+    not_equal!(1u64..3u64, 1u64..=3u64);
+}
+
+#[test]
+fn start_ne_end() {
+    not_equal!(0u64..5u64, 5u64..10u64);
+    not_equal!(0u64..=5u64, 5u64..=10u64);
+}
+
+#[test]
+fn range_same_as_int_width() {
+    equal!(138539813301742474259263044251542885032, "9daab8ffda90b62b32c4f895c87d76870f9b43fdfd93d86a866a45636a31e2ae";
+        10u32..20u32,
+        10u64..20u64,
+        10i16..20i16
+    );
+}
+
+#[test]
+fn bounds_ne() {
+    not_equal!(Bound::Included(0u32), Bound::Excluded(0u32));
+    not_equal!(Bound::Included(0u32), Bound::<u32>::Unbounded);
+    not_equal!(Bound::Excluded(0u32), Bound::<u32>::Unbounded);
+    not_equal!(Bound::Included(1u32), Bound::Excluded(1u32));
+    not_equal!(Bound::Included(1u32), Some(1u32));
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "serde_json")]
+
+mod common;
+
+use serde_json::{json, Value};
+
+fn parse(s: &str) -> Value {
+    serde_json::from_str(s).unwrap()
+}
+
+#[test]
+fn key_order() {
+    equal!(170353625601317369115481845703876802869, "0a22bebbc96e0d4c15804224e3e9a202c975714ccbd6cb8be6137f5a807f52c3";
+        parse(r#"{ "a": 1, "b": [true, null, "c"], "d": { "e": 1.5, "f": -2 } }"#),
+        parse(r#"{ "d": { "f": -2, "e": 1.5 }, "b": [true, null, "c"], "a": 1 }"#)
+    );
+}
+
+#[test]
+fn null_is_default() {
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        Value::Null,
+        Option::<u32>::None
+    );
+}
+
+#[test]
+fn defaults_ne_null() {
+    not_equal!(json!(false), Value::Null);
+    not_equal!(json!(0), Value::Null);
+    not_equal!(json!(""), Value::Null);
+    not_equal!(json!([]), Value::Null);
+    not_equal!(json!({}), Value::Null);
+    not_equal!(json!([]), json!({}));
+    not_equal!(json!(0), json!(false));
+    not_equal!(json!(""), json!([]));
+}
+
+#[test]
+fn numbers() {
+    assert_eq!(
+        common::fast_stable_hash(&parse("1")),
+        common::fast_stable_hash(&parse("1.0"))
+    );
+    assert_eq!(
+        common::fast_stable_hash(&parse("-3")),
+        common::fast_stable_hash(&parse("-3e0"))
+    );
+    not_equal!(parse("1"), parse("-1"));
+    not_equal!(parse("1.5"), parse("15"));
+    not_equal!(json!(u64::MAX), json!(i64::MIN));
+}
+
+#[test]
+fn number_same_as_int() {
+    use serde_json::Number;
+    equal!(300476818725221552349680556501826519020, "173097115007a0965e818effe3bc946da648604343807e529b1999b39a3a1e0b";
+        9u8,
+        Number::from(9u64),
+        Number::from_f64(9.0).unwrap()
+    );
+}
+
+#[test]
+fn values_ne() {
+    not_equal!(json!({ "a": 1, "b": 2 }), json!({ "a": 2, "b": 1 }));
+    not_equal!(json!([1, 2]), json!([2, 1]));
+    not_equal!(json!([1, null]), json!([1]));
+    not_equal!(json!({ "a": null }), json!({}));
+}
+
+#[cfg(feature = "serde_json-arbitrary-precision")]
+#[test]
+fn arbitrary_precision_exact() {
+    use stable_hash::utils::{AsDecimal, AsNumber};
+
+    equal!(94043943730587943033723364916668398198, "59ad16ff60e7114a6a2045a6709607b4eeab57bf7d9af75c7d95b8845246d1fe";
+        parse("1e400"),
+        parse("10E+399"),
+        parse("100.0e398")
+    );
+    let number = |s: &str| s.parse::<serde_json::Number>().unwrap();
+    assert_eq!(
+        common::fast_stable_hash(&number("1e400")),
+        common::fast_stable_hash(&AsDecimal {
+            is_negative: false,
+            little_endian: &[1],
+            scale: -400
+        })
+    );
+    let big: ibig::UBig = "123456789012345678901234567890".parse().unwrap();
+    assert_eq!(
+        common::fast_stable_hash(&number("123456789012345678901234567890")),
+        common::fast_stable_hash(&big)
+    );
+    not_equal!(parse("1e400"), parse("-1e400"));
+    not_equal!(parse("1e400"), parse("1e401"));
+
+    // Floats which fit in f64 hash the same as without arbitrary_precision.
+    assert_eq!(
+        common::fast_stable_hash(&number("0.1")),
+        common::fast_stable_hash(&AsNumber(0.1f64))
+    );
+}
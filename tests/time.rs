@@ -0,0 +1,46 @@
+mod common;
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[test]
+fn zero_is_default() {
+    equal!(
+        320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        Duration::ZERO,
+        UNIX_EPOCH,
+        Option::<Duration>::None
+    );
+}
+
+#[test]
+fn system_time_same_as_duration() {
+    let duration = Duration::new(1_600_000_000, 123_456_789);
+    equal!(
+        95959179847983699244629718784926432959, "2d76ee64fc9f371cde50acf55d83fe3cc1b96431b7aa8d738fc2de68d21c9c42";
+        duration,
+        UNIX_EPOCH + duration
+    );
+}
+
+#[test]
+fn before_epoch_ne() {
+    let duration = Duration::new(1, 500_000_000);
+    not_equal!(UNIX_EPOCH - duration, UNIX_EPOCH + duration);
+    not_equal!(UNIX_EPOCH - duration, duration);
+}
+
+#[test]
+fn seconds_ne_nanos() {
+    not_equal!(Duration::from_secs(1), Duration::from_nanos(1));
+    not_equal!(Duration::new(1, 2), Duration::new(2, 1));
+}
+
+#[test]
+fn now_is_consistent() {
+    let now = SystemTime::now();
+    let since = now.duration_since(UNIX_EPOCH).unwrap();
+    assert_eq!(
+        common::fast_stable_hash(&now),
+        common::fast_stable_hash(&since)
+    );
+}
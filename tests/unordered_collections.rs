@@ -1,6 +1,7 @@
 mod common;
 
-use std::collections::{HashMap, HashSet};
+use stable_hash::utils::AsSequence;
+use std::collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet};
 
 macro_rules! map(
     { $($key:expr => $value:expr),* } => {
@@ -85,3 +86,55 @@ fn hash_set_ne_count() {
 fn hash_set_ne_item() {
     not_equal!(set! {1, 2}, set! {3, 2})
 }
+
+#[test]
+fn btree_map_eq_hash_map() {
+    let mut btree = BTreeMap::new();
+    btree.insert(2, "two");
+    btree.insert(3, "three");
+    btree.insert(1, "one");
+    equal!(
+        60093794751952876589018848897648863192, "25130dd4633e3e9ff049594c26ca698f3a0513f9c14d98ff69744b8a2237ab9f";
+        btree,
+        map!{ 3 => "three", 1 => "one", 2 => "two" }
+    );
+}
+
+#[test]
+fn btree_set_eq_hash_set() {
+    equal!(
+        261168114195377271993952934537749440506, "4a87fcf3748ef16f7ebd64f1547d757a0b74c26d06a3368bcc03a8fce77734ef";
+        BTreeSet::from([2, 3, 1]),
+        set!{3, 2, 1}
+    );
+}
+
+#[test]
+fn as_sequence_eq_vec() {
+    let btree = BTreeMap::from([(2, "two"), (1, "one")]);
+    equal!(
+        7473632160252869781560866751215384622, "8f823bc07046fbbe59bce33bcdd7e2a6d6a7b5e01f7ef44ada575c10d499ead9";
+        AsSequence(&btree),
+        vec![(1, "one"), (2, "two")]
+    );
+    not_equal!(AsSequence(&btree), btree);
+}
+
+#[test]
+fn as_sequence_ne_count() {
+    not_equal!(
+        AsSequence(&BTreeSet::from([0, 1, 2])),
+        AsSequence(&BTreeSet::from([1, 2]))
+    );
+}
+
+#[test]
+fn binary_heap_eq() {
+    not_equal!(BinaryHeap::from([1, 2, 3]), BinaryHeap::from([1, 2, 3, 3]));
+    equal!(
+        261168114195377271993952934537749440506, "4a87fcf3748ef16f7ebd64f1547d757a0b74c26d06a3368bcc03a8fce77734ef";
+        BinaryHeap::from([1, 2, 3]),
+        BinaryHeap::from([3, 1, 2]),
+        set!{1, 2, 3}
+    );
+}
@@ -0,0 +1,43 @@
+#![cfg(any(feature = "uuid", feature = "bytes"))]
+
+mod common;
+
+use stable_hash::utils::AsBytes;
+
+#[cfg(feature = "uuid")]
+#[test]
+fn uuid_as_bytes() {
+    use uuid::Uuid;
+    let uuid = Uuid::from_u128(0xd3ba3adc_6e9b_4586_a7e7_6b542df39462);
+    equal!(9624604735577375689921127146623335827, "f91cb92b48d867f8b8e7bd365d4befd685be9a0a9bab4f37ce4ccf3ae974a6e6";
+        uuid,
+        AsBytes(uuid.as_bytes())
+    );
+    not_equal!(Uuid::nil(), Option::<Uuid>::None);
+    not_equal!(Uuid::nil(), Uuid::max());
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn bytes_are_opaque() {
+    use bytes::{Bytes, BytesMut};
+    equal!(225126920276043178050448780660223346301, "fe0d5d84754701b6cbb91451f9d8c6e589219ad8c04096ba7e4fe2e303671d59";
+        Bytes::from_static(b"abc"),
+        BytesMut::from(&b"abc"[..]),
+        AsBytes(b"abc"),
+        "abc"
+    );
+    not_equal!(Bytes::from_static(&[1, 2]), vec![1u8, 2u8]);
+    not_equal!(Bytes::from_static(&[0]), Bytes::new());
+}
+
+#[cfg(feature = "bytes")]
+#[test]
+fn empty_bytes_is_default() {
+    use bytes::{Bytes, BytesMut};
+    equal!(320514965852340112707580934281173047643, "48fc721fbbc172e0925fa27af1671de225ba927134802998b10a1568a188652b";
+        Bytes::new(),
+        BytesMut::new(),
+        Option::<Bytes>::None
+    );
+}